@@ -25,7 +25,9 @@ const N: usize = 10;
 
 const TOUR: usize = 5;
 
-const ITERS: usize = 10000;
+// NOTE: Both GAs are stopped after the same number of function evaluations rather than iterations, so that they are
+//       compared fairly. Cache hits do not count towards this budget (see `eval::Counted`).
+const EVALS: usize = 100_000;
 
 const PC: f64 = 0.5;
 
@@ -39,7 +41,7 @@ mod heur {
             insert::ElitistInserter,
             select::TournamentSelector,
         },
-        op::{self, Operator, init, population, stop::Iterations},
+        op::{self, Operator, init, population, stop::Evaluations},
         solution::{Evaluated, Individual},
     };
 
@@ -69,7 +71,7 @@ mod heur {
     fn ga(dim: usize) -> f64 {
         let sphere = Sphere { dim };
 
-        let mut eval = eval::from_fn(cost).cached();
+        let mut eval = eval::from_fn(cost).cached().counted();
 
         let mut rng = rand::rng();
 
@@ -83,7 +85,7 @@ mod heur {
             Ok(())
         });
         let insert = ElitistInserter::new();
-        let stop = Iterations::new(ITERS);
+        let stop = Evaluations::new(EVALS);
 
         let mut ga = op::hint(init).then(
             op::hint(select)
@@ -163,7 +165,7 @@ mod mahf {
                 deviation: 0.1,
                 pc: PC,
             },
-            LessThanN::iterations(ITERS as u32),
+            LessThanN::evaluations(EVALS as u32),
         )
         .unwrap();
 
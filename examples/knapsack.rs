@@ -1,11 +1,28 @@
 use heur::{
     Optimize,
-    bits::{FlipAllBits, SteepestAscentBitClimb},
-    eval::{self, Eval},
-    op::{self, Operator, accept::NonWorsening, init, stop::Iterations},
+    bits::{Flip, FlipAllBits, FlipBit, FlipNeighbourhood},
+    eval::{self, Eval, ToF64},
+    hyper::{SelectionHyperHeuristic, choice::ReinforcementLearning},
+    op::{
+        self,
+        Operator,
+        accept::NonWorsening,
+        adaptive::Success,
+        init,
+        search::{
+            BestImprovement,
+            tabu::{BetterThanBest, FixedTenure, TabuSearch},
+        },
+        stop::Iterations,
+    },
     solution::Individual,
 };
 
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
+
 use ordered_float::NotNan;
 
 use rand::distr::Bernoulli;
@@ -18,6 +35,12 @@ fn main() {
 
     // Solve the problem instance using iterated local search.
     ils(&knapsack);
+
+    // Solve the problem instance again using tabu search.
+    tabu(&knapsack);
+
+    // Solve the problem instance once more using a selection hyper-heuristic.
+    hyper(&knapsack);
 }
 
 // This represents the problem data we are given while solving. A knapsack problem instance consists of a list of items,
@@ -38,9 +61,74 @@ struct Item {
 // knapsack, its bit is set to `true` (1). Otherwise, its bit is set to `false` (0).
 type Solution = Vec<bool>;
 
+// The objective value of a solution. Only the cost is used to compare solutions, but we also keep track of the total weight
+// and value of the included items so that the cost of a neighbouring solution can be calculated incrementally (see `delta`
+// below).
+#[derive(Debug, Copy, Clone)]
+struct Cost {
+    cost: NotNan<f64>,
+    value: f64,
+    weight: f64,
+}
+
+impl Cost {
+    fn new(value: f64, weight: f64, knapsack: &Knapsack) -> Self {
+        // If the total weight of the included items is greater than the maximum capacity of the knapsack, we have an
+        // infeasible solution. The cost for infeasible solutions is represented as the negative of the total weight. This
+        // way, an infeasible solution is always going to be worse than a feasible one, since feasible solutions will only
+        // have positive values. Infeasible solutions can also be ordered - one infeasible solution is "less bad" than
+        // another if it has a lower included weight.
+        let cost = if weight > knapsack.capacity {
+            -weight
+        } else {
+            value
+        };
+
+        Self {
+            cost: NotNan::new(cost).unwrap(),
+            value,
+            weight,
+        }
+    }
+}
+
+impl PartialEq for Cost {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl Eq for Cost {}
+
+impl PartialOrd for Cost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost.cmp(&other.cost)
+    }
+}
+
+// Acceptance criteria that measure by how much the objective value changed (such as `SimulatedAnnealing` or
+// `ThresholdAccepting`) need to turn it into a number first. Only the cost matters here, just like when comparing costs.
+impl ToF64 for Cost {
+    fn to_f64(&self) -> f64 {
+        *self.cost
+    }
+}
+
+impl Display for Cost {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        self.cost.fmt(formatter)
+    }
+}
+
 // An objective function that calculates the cost, or objective value, of a given solution (`Vec<bool>`) to a knapsack problem
 // instance (`Knapsack`).
-fn cost(solution: &Solution, knapsack: &Knapsack) -> NotNan<f64> {
+fn cost(solution: &Solution, knapsack: &Knapsack) -> Cost {
     // Calculate the total weight and value of the items in the knapsack by summing them up together. Only the items
     // that are included (i.e. whose bits in the solution are `true`) are counted.
     let (value, weight) = solution
@@ -52,18 +140,24 @@ fn cost(solution: &Solution, knapsack: &Knapsack) -> NotNan<f64> {
             (value + item.value, weight + item.weight)
         });
 
-    // If the total weight of the included items is greater than the maximum capacity of the knapsack, we have an infeasible
-    // solution. The cost for infeasible solutions is represented as the negative of the total weight. This way, an infeasible
-    // solution is always going to be worse than a feasible one, since feasible solutions will only have positive values.
-    // Infeasible solutions can also be ordered - one infeasible solution is "less bad" than another if it has a lower included
-    // weight.
-    let cost = if weight > knapsack.capacity {
-        -weight
-    } else {
-        value
-    };
+    Cost::new(value, weight, knapsack)
+}
+
+// Calculates the cost of the solution obtained by flipping a single bit of the given solution, whose cost is already known.
+// Rather than summing up all included items again, we only need to add or remove the flipped item, which is O(1) instead
+// of O(n). Search operators use this through `Delta` instead of evaluating neighbouring solutions from scratch once they
+// are told to (see `BestImprovement::incremental` below).
+fn delta(solution: &Solution, knapsack: &Knapsack, cost: &Cost, flip: &Flip) -> Cost {
+    let Flip(idx) = *flip;
+    let item = &knapsack.items[idx];
 
-    NotNan::new(cost).unwrap()
+    // If the item is currently included, flipping its bit removes it from the knapsack, and vice versa.
+    let sign = if solution[idx] { -1.0 } else { 1.0 };
+    Cost::new(
+        cost.value + sign * item.value,
+        cost.weight + sign * item.weight,
+        knapsack,
+    )
 }
 
 fn ils(knapsack: &Knapsack) {
@@ -71,27 +165,41 @@ fn ils(knapsack: &Knapsack) {
     // the objective function above, but you could create a custom type and impl `Eval` for it manually like so:
     //
     // ```rs
-    // struct Cost;
+    // struct CostFn;
     //
-    // impl Eval<Knapsack, Vec<bool>> for Cost {
-    //     type Objective = f64;
+    // impl Eval<Knapsack, Vec<bool>> for CostFn {
+    //     type Objective = Cost;
     //
-    //     fn eval(&mut self, solution: &Vec<bool>, knapsack: &Knapsack) -> f64 { ... }
+    //     fn eval(&mut self, solution: &Vec<bool>, knapsack: &Knapsack) -> Cost { ... }
     // }
     // ```
-    let mut eval = eval::from_fn(cost);
+    //
+    // `Eval::with_delta` adds incremental evaluation of single bit flips using `delta` above. A custom type would impl
+    // `Delta<Knapsack, Vec<bool>, Flip>` and override `Delta::eval_move` instead.
+    //
+    // Objective values are maximised by default, so `Eval::maximize` isn't strictly needed here - but it makes the
+    // direction explicit. Costs that should be minimised can use `Eval::minimize` instead of being negated.
+    let mut eval = eval::from_fn(cost).with_delta(delta).maximize();
 
     // Define the various operators we will be using for the iterated local search metaheuristic. We initialise the solution
     // using an all-zeros bitstring (i.e. no items are included at the start).
     //
     // We use a bit-flipping operator as the mutation (aka perturbation) operator, with a 0.002 (0.2%) probability of flipping
-    // each bit, and a steepest ascent hill climb as the local search operator.
+    // each bit, and a steepest ascent hill climb over single bit flips as the local search operator, which evaluates the
+    // flips incrementally using `delta` above (see `BestImprovement::incremental`). The hill climb only takes a single step each
+    // time it is applied, so we use `Operator::until_local_optimum` to keep climbing until no neighbouring solution is
+    // better. `.max_steps(n)` can be used to limit the number of steps taken. To mix several mutation operators, pick one at
+    // random each time using `op::choose_weighted`, e.g. `op::choose_weighted((FlipBit::new(rng), FlipAllBits::new(...)),
+    // [3.0, 1.0], rng)?` to choose the first operator three times as often as the second.
     //
     // Any changes made by the mutation and local search operators are only accepted if they are non-worsening (i.e. produce an
-    // objective value that is no worse than the previous known value), and we stop when we get to 1000 iterations.
+    // objective value that is no worse than the previous known value), and we stop when we get to 1000 iterations. Swapping
+    // `NonWorsening::new()` for `SimulatedAnnealing::new(Geometric::new(1000.0, 0.99), rand::rng())` would turn this into a
+    // simulated annealing-based search instead (which uses the `ToF64` impl for `Cost` above).
     let init = init::from_individual(vec![false; knapsack.items.len()]);
     let mutate = FlipAllBits::new(Bernoulli::new(0.002).unwrap(), rand::rng());
-    let local_search = SteepestAscentBitClimb::new();
+    let local_search = op::hint(BestImprovement::new(FlipNeighbourhood::new()).incremental())
+        .until_local_optimum();
     let accept = NonWorsening::new();
     let stop = Iterations::new(1000);
 
@@ -147,6 +255,82 @@ fn ils(knapsack: &Knapsack) {
     println!("found solution with objective value of {}", objective);
 }
 
+fn tabu(knapsack: &Knapsack) {
+    // We use the same objective function (and incremental evaluation of bit flips) as above.
+    let mut eval = eval::from_fn(cost).with_delta(delta).maximize();
+
+    // Tabu search explores the same neighbourhood as the bit climb above (all solutions that are a single bit flip away),
+    // but always moves to the best neighbouring solution, even if it is worse than the current one. To avoid undoing its
+    // own moves, bits that were recently flipped become tabu (i.e. cannot be flipped again) for the next 20 iterations -
+    // unless flipping them would lead to a new best solution (the aspiration criterion).
+    let init = init::from_individual(vec![false; knapsack.items.len()]);
+    let tabu_search = TabuSearch::new(
+        FlipNeighbourhood::new(),
+        FixedTenure::new(20),
+        BetterThanBest::new(),
+    )
+    .incremental();
+    let stop = Iterations::new(1000);
+
+    // Since tabu search can move to worse solutions, the final solution is not necessarily the best one found during the
+    // search. `RepeatUntil::keep_best` keeps track of the best solution and returns it instead.
+    let mut tabu = op::hint(init).then(op::hint(tabu_search).repeat_until(stop).keep_best());
+
+    let solution: Individual<Solution> = tabu.optimize(knapsack, &mut eval).unwrap();
+
+    let objective = eval.eval(&solution, knapsack);
+    println!("found solution with objective value of {}", objective);
+}
+
+fn hyper(knapsack: &Knapsack) {
+    // We use the same objective function (and incremental evaluation of bit flips) as above.
+    let mut eval = eval::from_fn(cost).with_delta(delta).maximize();
+
+    // A selection hyper-heuristic chooses between a pool of low-level heuristics every iteration rather than applying
+    // them in a fixed order. Here, the pool contains the two mutation operators and the local search operator from above,
+    // which are boxed so that they can be stored in the same `Vec` (they need to have the same output and error types).
+    // Using `boxed_reset` rather than `boxed` keeps them resettable, so that the hyper-heuristic can be run again.
+    let init = init::from_individual(vec![false; knapsack.items.len()]);
+    let heuristics = vec![
+        op::hint(FlipBit::new(rand::rng())).boxed_reset(),
+        op::hint(FlipAllBits::new(
+            Bernoulli::new(0.002).unwrap(),
+            rand::rng(),
+        ))
+        .boxed_reset(),
+        op::hint(BestImprovement::new(FlipNeighbourhood::new()).incremental())
+            .until_local_optimum()
+            .boxed_reset(),
+    ];
+
+    // The choice function decides which heuristic to apply next, based on how well each of them has done so far. A
+    // heuristic is rewarded whenever it improves the solution (see `Success`), and its utility goes up or down depending
+    // on whether it was rewarded (see `ReinforcementLearning`). Other choice functions can be found in `hyper::choice`.
+    // Like the iterated local search above, only non-worsening solutions are accepted and we stop after 1000 iterations.
+    let choice = ReinforcementLearning::new(5, 10, rand::rng());
+    let mut hyper = SelectionHyperHeuristic::new(
+        init,
+        heuristics,
+        choice,
+        Success::new(),
+        NonWorsening::new(),
+        Iterations::new(1000),
+    );
+
+    let solution: Individual<Solution> = hyper.optimize(knapsack, &mut eval).unwrap();
+
+    let objective = eval.eval(&solution, knapsack);
+    println!("found solution with objective value of {}", objective);
+
+    // The hyper-heuristic keeps track of how often each heuristic was applied and how often it improved the solution.
+    for (idx, stats) in hyper.stats().iter().enumerate() {
+        println!(
+            "heuristic {idx} was applied {} times and improved the solution {} times",
+            stats.applications, stats.successes,
+        );
+    }
+}
+
 // This code parses a knapsack problem instance from a string - an example instance file is at `../instances/knapsack.in`.
 fn parse_knapsack(instance: &str) -> Knapsack {
     let mut lines = instance.lines();
@@ -165,3 +349,46 @@ fn parse_knapsack(instance: &str) -> Knapsack {
 
     Knapsack { items, capacity }
 }
+
+#[cfg(test)]
+mod tests {
+    use heur::op::search::Search;
+
+    use super::*;
+
+    fn knapsack() -> Knapsack {
+        let items = [(10.0, 5.0), (6.0, 4.0), (5.0, 3.0)]
+            .map(|(value, weight)| Item { value, weight })
+            .into();
+        Knapsack {
+            items,
+            capacity: 8.0,
+        }
+    }
+
+    // NOTE: Starting from the same solution, the hill climb used above moves towards the most valuable feasible solution
+    //       when maximising, but towards including every item (the heaviest infeasible solution) when minimising.
+    fn climb<E>(knapsack: &Knapsack, eval: &mut E) -> (Solution, f64)
+    where
+        E: Eval<Knapsack, Solution, Objective = Cost> + heur::eval::Delta<Knapsack, Solution, Flip>,
+    {
+        let mut solution = Individual::new(vec![true, true, false]);
+        let mut local_search =
+            op::hint(BestImprovement::new(FlipNeighbourhood::new()).incremental())
+                .until_local_optimum();
+        local_search.search(&mut solution, knapsack, eval).unwrap();
+        let objective = eval.eval(&solution, knapsack);
+        (Individual::into_inner(solution), *objective.cost)
+    }
+
+    #[test]
+    fn climb_follows_direction() {
+        let knapsack = knapsack();
+
+        let mut max = eval::from_fn(cost).with_delta(delta).maximize();
+        assert_eq!(climb(&knapsack, &mut max), (vec![true, false, true], 15.0));
+
+        let mut min = eval::from_fn(cost).with_delta(delta).minimize();
+        assert_eq!(climb(&knapsack, &mut min), (vec![true, true, true], -12.0));
+    }
+}
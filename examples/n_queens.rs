@@ -1,6 +1,7 @@
 use std::array;
 
 use heur::{
+    Error,
     Optimize,
     eval::{self, Eval},
     genetic::{
@@ -42,23 +43,21 @@ type Solution = Vec<Pos>;
 
 // An objective function that calculates the cost, aka objective value, of a given solution (`Vec<Pos>`) to an N-queens problem
 // instance (`Problem`).
-fn cost(positions: &Solution, _problem: &Problem) -> isize {
-    let collisions = positions
+fn cost(positions: &Solution, _problem: &Problem) -> usize {
+    positions
         .iter()
         .enumerate()
         .flat_map(|lhs| positions.iter().enumerate().map(move |rhs| (lhs, rhs)))
         .filter_map(|((idx_lhs, lhs), (idx_rhs, rhs))| (idx_lhs != idx_rhs).then_some((lhs, rhs)))
+        // Two queens are on the same diagonal if `lhs.x - lhs.y == rhs.x - rhs.y`, which is rearranged so that the
+        // unsigned subtraction cannot underflow.
         .filter(|(lhs, rhs)| {
             lhs.x == rhs.x
                 || lhs.y == rhs.y
                 || lhs.x + lhs.y == rhs.x + rhs.y
-                || lhs.x - lhs.y == rhs.x - rhs.y
+                || lhs.x + rhs.y == rhs.x + lhs.y
         })
-        .count();
-
-    // Since `heur` assumes that the objective is being maximised, we negate the number of collisions. This way, the
-    // highest possible objective value is 0, with lower objective values indicating worse solutions.
-    -(collisions as isize)
+        .count()
 }
 
 // A helper function to generate a random (potentially infeasible) solution to an N-queens problem instance. This is
@@ -94,12 +93,19 @@ fn ga(problem: &Problem) {
     // struct Cost;
     //
     // impl Eval<Problem, Vec<Pos>> for Cost {
-    //     type Objective = isize;
+    //     type Objective = usize;
+    //
+    //     fn eval(&mut self, solution: &Vec<Pos>, problem: &Problem) -> usize { ... }
     //
-    //     fn eval(&mut self, solution: &Vec<Pos>, problem: &Problem) -> isize { ... }
+    //     fn direction(&self) -> Direction {
+    //         Direction::Minimize
+    //     }
     // }
     // ```
-    let mut eval = eval::from_fn(cost);
+    //
+    // Objective values are maximised by default, but here we want to minimise the number of collisions, so we use
+    // `Eval::minimize`. All built-in operators (selection, insertion, stop conditions, etc.) respect this.
+    let mut eval = eval::from_fn(cost).minimize();
 
     let mut rng = rand::rng();
 
@@ -141,12 +147,16 @@ fn ga(problem: &Problem) {
     //
     // Constructing metaheuristics via combinators will generally produce the same code, as the compiler is able to easily
     // inline and optimise away the various layers of wrappers.
-    let mut ga = op::hint(init).then(
+    //
+    // Combined operators must have the same error type. Uniform crossover can fail (if it is given an odd number of
+    // individuals), while the other operators cannot, so we convert all of their errors into `heur::Error` using
+    // `Operator::err_into`, which wraps the errors of every built-in operator.
+    let mut ga = op::hint(init).err_into::<Error>().then(
         op::hint(select)
-            .unwrapped()
-            .pipe(op::hint(combine).unwrapped())
+            .err_into()
+            .pipe(op::hint(combine).err_into())
             .pipe(on_combined(population::for_each(mutate)))
-            .pipe(insert)
+            .pipe(op::hint(insert).err_into())
             .repeat_until(stop),
     );
 
@@ -154,19 +164,84 @@ fn ga(problem: &Problem) {
     // impls `Eval<P, S>` where `S` is the solution type and `P` is the problem type), and we get back a solution (or an error
     // if something went wrong during solving).
     //
-    // In this case, all operators chosen above have an error type of `Infallible`, so the error type of their combination is
-    // also `Infallible` and we can safely unwrap the result. For more complex operators, they may return errors, which you
-    // would want to handle properly.
+    // In this case, the only error that can occur is uniform crossover being given an odd number of individuals, which
+    // cannot happen since we always select 50 individuals, so we can safely unwrap the result. For more complex operators,
+    // they may return errors, which you would want to handle properly.
     //
     // Since we started with a population of individuals (see `init::from_population`), we get back a population as well.
     let population: [Solution; 100] = ga.optimize(problem, &mut eval).unwrap();
 
-    // Evaluate the best individual from the population. Since our stop condition is finding an optimal solution, this
-    // individual will have the optimal objective value of 0.
+    // Evaluate the best individual from the population (i.e. the one with the fewest collisions). Since our stop condition
+    // is finding an optimal solution, this individual will have the optimal objective value of 0.
     let best_objective = population
         .iter()
         .map(|solution| eval.eval(solution, problem))
-        .max()
+        .min()
         .unwrap();
     println!("found solution with objective value of {}", best_objective);
 }
+
+#[cfg(test)]
+mod tests {
+    use heur::op::stop::{Iterations, Stop};
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    // NOTE: This is the genetic algorithm from `ga` above on a smaller population, using a seeded RNG so that it always
+    //       finds the same solution. It gives up after 1000 iterations, so that it stops even if it searches in the wrong
+    //       direction.
+    fn solve<E>(problem: &Problem, eval: &mut E, optimum: E::Objective) -> Vec<Solution>
+    where
+        E: Eval<Problem, Solution, Objective: Ord>,
+        Optimum<E::Objective>: Stop<Problem, [Solution; 20], E>,
+        Iterations: Stop<Problem, [Solution; 20], E>,
+    {
+        let mut rng = StdRng::seed_from_u64(0);
+        let population: [Solution; 20] = array::from_fn(|_| init_random(problem, &mut rng));
+        let select = ElitistSelector::new(10);
+        let combine = UniformCrossover::new(Bernoulli::new(0.5).unwrap(), rng.clone());
+        let mutate = op::from_fn(|solution, problem, _, _| {
+            apply_mutation(solution, problem, &mut rng);
+            Ok(())
+        });
+
+        let mut ga = op::hint(init::from_population(population))
+            .err_into::<Error>()
+            .then(
+                op::hint(select)
+                    .err_into()
+                    .pipe(op::hint(combine).err_into())
+                    .pipe(on_combined(population::for_each(mutate)))
+                    .pipe(op::hint(ElitistInserter::new()).err_into())
+                    .repeat_until(Optimum::new(optimum).or(Iterations::new(1000))),
+            );
+
+        let population: [Solution; 20] = ga.optimize(problem, eval).unwrap();
+        population.into()
+    }
+
+    #[test]
+    fn finds_optimum_in_either_direction() {
+        let problem = Problem { n_queens: 5 };
+
+        // Minimising the number of collisions
+        let mut min = eval::from_fn(cost).minimize();
+        let population = solve(&problem, &mut min, 0);
+        assert!(
+            population
+                .iter()
+                .any(|solution| cost(solution, &problem) == 0)
+        );
+
+        // Maximising the negated number of collisions instead, which has the same optimum
+        let negated = |solution: &Solution, problem: &Problem| -(cost(solution, problem) as isize);
+        let mut max = eval::from_fn(negated).maximize();
+        let population = solve(&problem, &mut max, 0);
+        assert!(
+            population
+                .iter()
+                .any(|solution| cost(solution, &problem) == 0)
+        );
+    }
+}
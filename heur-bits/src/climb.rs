@@ -1,131 +1,137 @@
-use core::convert::Infallible;
+use core::{iter::Map, ops::Range};
 
-use heur_core::{
-    eval::Eval,
-    op::{Operator, search::Search},
-    solution::Individual,
-};
+use rand::Rng;
 
-use crate::Bits;
+use heur_core::op::search::{BestImprovement, FirstImprovement, Neighbourhood};
 
+use crate::{Bits, Flip};
+
+// NOTE: This flips the first bit that improves on the current solution. Like any `FirstImprovement`, it can also evaluate
+//       flips incrementally (see `FirstImprovement::incremental`).
+pub type FirstAscentBitClimb = FirstImprovement<FlipNeighbourhood>;
+
+// NOTE: This flips the bit that improves the most on the current solution, if any. Like any `BestImprovement`, it can also
+//       evaluate flips incrementally (see `BestImprovement::incremental`).
+pub type SteepestAscentBitClimb = BestImprovement<FlipNeighbourhood>;
+
+// NOTE: This is the neighbourhood of all solutions that differ from a solution by exactly one bit.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
-pub struct FirstAscentBitClimb;
+pub struct FlipNeighbourhood;
 
-impl FirstAscentBitClimb {
+impl FlipNeighbourhood {
     pub fn new() -> Self {
         Self
     }
 }
 
-impl<P, B, E> Operator<P, Individual<B>, E> for FirstAscentBitClimb
+impl<P, B> Neighbourhood<P, B> for FlipNeighbourhood
 where
     B: Bits,
-    E: Eval<P, B>,
 {
-    type Output = ();
-
-    type Error = Infallible;
-
-    fn apply(
-        &mut self,
-        solution: &mut Individual<B>,
-        problem: &P,
-        eval: &mut E,
-        _input: (),
-    ) -> Result<Self::Output, Self::Error> {
-        self.search(solution, problem, eval)
+    type Move = Flip;
+
+    type Moves = Map<Range<usize>, fn(usize) -> Flip>;
+
+    fn moves(&mut self, solution: &B, _problem: &P) -> Self::Moves {
+        (0..solution.len()).map(Flip)
     }
-}
 
-impl<P, B, E> Search<P, Individual<B>, E> for FirstAscentBitClimb
-where
-    B: Bits,
-    E: Eval<P, B>,
-{
-    fn search(
-        &mut self,
-        solution: &mut Individual<B>,
-        problem: &P,
-        eval: &mut E,
-    ) -> Result<(), Self::Error> {
-        let solution = &mut **solution;
-
-        let objective = eval.eval(solution, problem);
-
-        // NOTE: We iterate over indices here because it's not possible to iterate over the solution mutably
-        //       while also passing it immutably to `eval` for evaluation. Also because `Bits` doesn't expose
-        //       an API for mutably iterating over bits.
-        let next = (0..solution.len()).find_map(|idx| {
-            let bit = solution.flip(idx).unwrap(); // PANICS: We know that the index is valid
-            let improved = (eval.eval(solution, problem) > objective).then_some(idx);
-            solution.set(idx, bit).unwrap(); // PANICS: Same as above
-            improved
-        });
-
-        if let Some(idx) = next {
-            solution.flip(idx).unwrap(); // PANICS: We know that the index is valid from above
-        }
-
-        Ok(())
+    fn sample<R>(&mut self, solution: &B, _problem: &P, rng: &mut R) -> Option<Self::Move>
+    where
+        R: Rng + ?Sized,
+    {
+        // NOTE: We need to check that the solution is not empty, because `Rng::random_range` panics on empty ranges.
+        (!solution.is_empty()).then(|| Flip(rng.random_range(0..solution.len())))
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
-#[must_use]
-pub struct SteepestAscentBitClimb;
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
 
-impl SteepestAscentBitClimb {
-    pub fn new() -> Self {
-        Self
+    use heur_core::{
+        eval::{self, Counter, Eval},
+        op::search::{RandomImprovement, Search},
+        solution::Individual,
+    };
+
+    use super::*;
+
+    // NOTE: Later bits are worth more, so first and best improvements flip different bits.
+    fn weighted(solution: &[bool; 3], _problem: &()) -> usize {
+        (solution.iter().enumerate())
+            .filter(|(_, bit)| **bit)
+            .map(|(idx, _)| idx + 1)
+            .sum()
     }
-}
 
-impl<P, B, E> Operator<P, Individual<B>, E> for SteepestAscentBitClimb
-where
-    B: Bits,
-    E: Eval<P, B, Objective: Ord>,
-{
-    type Output = ();
-
-    type Error = Infallible;
-
-    fn apply(
-        &mut self,
-        solution: &mut Individual<B>,
-        problem: &P,
-        eval: &mut E,
-        _input: (),
-    ) -> Result<Self::Output, Self::Error> {
-        self.search(solution, problem, eval)
+    type Weighted = eval::FromFn<fn(&[bool; 3], &()) -> usize>;
+
+    fn climb<T>(mut op: T, bits: [bool; 3], maximize: bool) -> ([bool; 3], bool)
+    where
+        T: Search<(), Individual<[bool; 3]>, eval::Maximize<Weighted>>
+            + Search<(), Individual<[bool; 3]>, eval::Minimize<Weighted>>,
+    {
+        let eval: Weighted = eval::from_fn(weighted);
+        let mut solution = Individual::new(bits);
+        let improved = if maximize {
+            op.search(&mut solution, &(), &mut eval.maximize()).unwrap()
+        } else {
+            op.search(&mut solution, &(), &mut eval.minimize()).unwrap()
+        };
+        (Individual::into_inner(solution), improved)
     }
-}
 
-impl<P, B, E> Search<P, Individual<B>, E> for SteepestAscentBitClimb
-where
-    B: Bits,
-    E: Eval<P, B, Objective: Ord>,
-{
-    fn search(
-        &mut self,
-        solution: &mut Individual<B>,
-        problem: &P,
-        eval: &mut E,
-    ) -> Result<(), Self::Error> {
-        let solution = &mut **solution;
-
-        // NOTE: See the note above regarding iteration over indices.
-        let best = (0..solution.len()).max_by_key(|&idx| {
-            let bit = solution.flip(idx).unwrap(); // PANICS: We know that the index is valid from above
-            let objective = eval.eval(solution, problem);
-            solution.set(idx, bit).unwrap(); // PANICS: Same as above
-            objective
-        });
-
-        if let Some(idx) = best {
-            solution.flip(idx).unwrap(); // PANICS: We know that the index is valid from above
-        }
-
-        Ok(())
+    #[test]
+    fn first_ascent_follows_direction() {
+        let op = FirstAscentBitClimb::default();
+        assert_eq!(climb(op, [false; 3], true), ([true, false, false], true));
+        assert_eq!(climb(op, [true; 3], false), ([false, true, true], true));
+        assert_eq!(climb(op, [false; 3], false), ([false; 3], false));
+        assert_eq!(
+            climb(op.incremental(), [true; 3], false),
+            ([false, true, true], true)
+        );
+    }
+
+    #[test]
+    fn steepest_ascent_follows_direction() {
+        let op = SteepestAscentBitClimb::default();
+        assert_eq!(climb(op, [false; 3], true), ([false, false, true], true));
+        assert_eq!(climb(op, [true; 3], false), ([true, true, false], true));
+        assert_eq!(climb(op, [true; 3], true), ([true; 3], false));
+        assert_eq!(
+            climb(op.incremental(), [true; 3], false),
+            ([true, true, false], true)
+        );
+    }
+
+    #[test]
+    fn random_improvement_flips_sampled_improving_bit() {
+        let op = RandomImprovement::new(FlipNeighbourhood::new(), 3, StdRng::seed_from_u64(0));
+        let (bits, improved) = climb(op.clone(), [false; 3], true);
+        assert!(improved);
+        assert_eq!(bits.iter().filter(|bit| **bit).count(), 1);
+
+        let (bits, improved) = climb(op.incremental(), [false; 3], false);
+        assert_eq!((bits, improved), ([false; 3], false));
+
+        let op = RandomImprovement::new(FlipNeighbourhood::new(), 0, StdRng::seed_from_u64(0));
+        assert_eq!(climb(op, [false; 3], true), ([false; 3], false));
+    }
+
+    #[test]
+    fn random_improvement_samples_at_most_given_number_of_moves() {
+        let mut op = RandomImprovement::new(FlipNeighbourhood::new(), 5, StdRng::seed_from_u64(0));
+        let eval: Weighted = eval::from_fn(weighted);
+        let mut eval = eval.counted();
+        let mut solution = Individual::new([true; 3]);
+        assert!(!op.search(&mut solution, &(), &mut eval).unwrap());
+        assert_eq!(*solution, [true; 3]);
+
+        // NOTE: The current solution is evaluated once, followed by every sampled neighbour.
+        assert_eq!(eval.evaluations(), 6);
     }
 }
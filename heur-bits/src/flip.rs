@@ -5,15 +5,51 @@ use rand::{
     distr::{Bernoulli, Distribution},
 };
 
+#[cfg(feature = "alloc")]
+use heur_core::op::search::tabu::MoveAttribute;
+
 use heur_core::{
     eval::Eval,
-    op::{Operator, mutate::Mutate},
-    solution::Individual,
+    op::{
+        Operator,
+        Reset,
+        mutate::{Incremental, MoveMutation, Mutate},
+    },
+    solution::{Individual, Move},
 };
 
 use crate::Bits;
 
+// NOTE: Flipping a bit at an out-of-bounds index does nothing, like `Bits::flip`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Flip(pub usize);
+
+impl<B> Move<B> for Flip
+where
+    B: Bits + ?Sized,
+{
+    fn apply(&self, solution: &mut B) {
+        solution.flip(self.0);
+    }
+
+    fn undo(&self, solution: &mut B) {
+        solution.flip(self.0);
+    }
+}
+
+// NOTE: Flipping a bit makes flipping it back tabu, regardless of whether it was set or unset.
+#[cfg(feature = "alloc")]
+impl MoveAttribute for Flip {
+    type Attribute = usize;
+
+    fn attribute(&self) -> Self::Attribute {
+        self.0
+    }
+}
+
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct FlipBit<R> {
     rng: R,
@@ -23,6 +59,10 @@ impl<R> FlipBit<R> {
     pub fn new(rng: R) -> Self {
         Self { rng }
     }
+
+    pub fn incremental(self) -> Incremental<Self> {
+        Incremental::new(self)
+    }
 }
 
 impl<P, B, E, R> Operator<P, Individual<B>, E> for FlipBit<R>
@@ -55,19 +95,35 @@ where
     fn mutate(
         &mut self,
         solution: &mut Individual<B>,
-        _problem: &P,
+        problem: &P,
         _eval: &mut E,
     ) -> Result<(), Self::Error> {
-        // NOTE: We need to check that the solution is not empty, because `Rng::gen_range` panics on empty ranges.
+        self.mutate_with(&mut **solution, problem, |solution, mv| mv.apply(solution));
+        Ok(())
+    }
+}
+
+impl<P, B, R> MoveMutation<P, B> for FlipBit<R>
+where
+    B: Bits,
+    R: Rng,
+{
+    type Move = Flip;
+
+    fn mutate_with<F>(&mut self, solution: &mut B, _problem: &P, mut apply: F)
+    where
+        F: FnMut(&mut B, &Self::Move),
+    {
+        // NOTE: We need to check that the solution is not empty, because `Rng::random_range` panics on empty ranges.
         if !solution.is_empty() {
             let idx = self.rng.random_range(0..solution.len());
-            solution.flip(idx).unwrap(); // PANICS: We know that the index is valid
+            apply(solution, &Flip(idx));
         }
-        Ok(())
     }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct FlipAllBits<R> {
     dist: Bernoulli,
@@ -78,6 +134,11 @@ impl<R> FlipAllBits<R> {
     pub fn new(dist: Bernoulli, rng: R) -> Self {
         Self { dist, rng }
     }
+
+    // NOTE: Every bit is flipped (and evaluated) separately, so this is only worthwhile if few bits are flipped at a time.
+    pub fn incremental(self) -> Incremental<Self> {
+        Incremental::new(self)
+    }
 }
 
 impl<P, B, E, R> Operator<P, Individual<B>, E> for FlipAllBits<R>
@@ -110,14 +171,134 @@ where
     fn mutate(
         &mut self,
         solution: &mut Individual<B>,
-        _problem: &P,
+        problem: &P,
         _eval: &mut E,
     ) -> Result<(), Self::Error> {
+        self.mutate_with(&mut **solution, problem, |solution, mv| mv.apply(solution));
+        Ok(())
+    }
+}
+
+impl<P, B, R> MoveMutation<P, B> for FlipAllBits<R>
+where
+    B: Bits,
+    R: Rng,
+{
+    type Move = Flip;
+
+    fn mutate_with<F>(&mut self, solution: &mut B, _problem: &P, mut apply: F)
+    where
+        F: FnMut(&mut B, &Self::Move),
+    {
         for idx in 0..solution.len() {
             if self.dist.sample(&mut self.rng) {
-                solution.flip(idx).unwrap(); // PANICS: We know that the index is valid
+                apply(solution, &Flip(idx));
             }
         }
-        Ok(())
+    }
+}
+
+impl<R> Reset for FlipBit<R> {
+    fn reset(&mut self) {}
+}
+
+impl<R> Reset for FlipAllBits<R> {
+    fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use heur_core::{
+        eval::{self, Counter, Delta},
+        op::search::Search,
+        solution::Evaluated,
+    };
+
+    use crate::FirstAscentBitClimb;
+
+    use super::*;
+
+    type Bitstring = Evaluated<[bool; 8], usize>;
+
+    fn ones(solution: &[bool; 8], _problem: &()) -> usize {
+        solution.iter().filter(|bit| **bit).count()
+    }
+
+    fn delta(solution: &[bool; 8], _problem: &(), objective: &usize, mv: &Flip) -> usize {
+        if solution[mv.0] {
+            objective - 1
+        } else {
+            objective + 1
+        }
+    }
+
+    // NOTE: Only the evaluations of `ones` are counted, and not the flips evaluated incrementally using `delta`.
+    fn cached() -> impl Delta<(), Bitstring, Flip, Objective = usize> + Counter {
+        let ones: fn(&[bool; 8], &()) -> usize = ones;
+        let delta: fn(&[bool; 8], &(), &usize, &Flip) -> usize = delta;
+        eval::from_fn(ones).counted().with_delta(delta).cached()
+    }
+
+    fn mutate<T, E>(mut op: T, eval: &mut E) -> usize
+    where
+        T: Mutate<(), Individual<Bitstring>, E>,
+        E: Eval<(), Bitstring, Objective = usize>,
+    {
+        let mut solution = Individual::new(Evaluated::new([false; 8]));
+        assert_eq!(eval.eval(&solution, &()), 0);
+        for _ in 0..10 {
+            op.mutate(&mut solution, &(), eval).unwrap();
+            assert_eq!(eval.eval(&solution, &()), ones(&solution, &()));
+        }
+        eval.eval(&solution, &())
+    }
+
+    #[test]
+    fn incremental_flips_keep_cached_objective() {
+        let rng = StdRng::seed_from_u64(0);
+
+        let mut eval = cached();
+        let objective = mutate(FlipBit::new(rng.clone()), &mut eval);
+        assert_eq!(eval.evaluations(), 11);
+
+        let mut incremental = cached();
+        assert_eq!(
+            mutate(FlipBit::new(rng.clone()).incremental(), &mut incremental),
+            objective
+        );
+        assert_eq!(
+            (incremental.evaluations(), incremental.cache_hits()),
+            (1, 11)
+        );
+
+        let dist = Bernoulli::new(0.5).unwrap();
+        let mut eval = cached();
+        let objective = mutate(FlipAllBits::new(dist, rng.clone()), &mut eval);
+        assert_eq!(eval.evaluations(), 11);
+
+        let mut incremental = cached();
+        let mutation = FlipAllBits::new(dist, rng).incremental();
+        assert_eq!(mutate(mutation, &mut incremental), objective);
+        assert_eq!(incremental.evaluations(), 1);
+    }
+
+    #[test]
+    fn incremental_climb_evaluates_flips_incrementally() {
+        let mut solution = Individual::new(Evaluated::new([false; 8]));
+
+        let mut eval = cached();
+        let mut climb = FirstAscentBitClimb::default();
+        assert!(climb.search(&mut solution, &(), &mut eval).unwrap());
+        assert_eq!(eval.evaluations(), 2);
+
+        let mut eval = cached();
+        let mut climb = FirstAscentBitClimb::default().incremental();
+        assert!(climb.search(&mut solution, &(), &mut eval).unwrap());
+        assert_eq!(eval.evaluations(), 1);
+        assert!(climb.search(&mut solution, &(), &mut eval).unwrap());
+        assert_eq!(eval.evaluations(), 1);
+        assert_eq!(solution.objective(), Some(3));
     }
 }
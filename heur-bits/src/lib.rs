@@ -13,10 +13,10 @@ use core::mem;
 use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
 
 mod flip;
-pub use flip::{FlipAllBits, FlipBit};
+pub use flip::{Flip, FlipAllBits, FlipBit};
 
 mod climb;
-pub use climb::{FirstAscentBitClimb, SteepestAscentBitClimb};
+pub use climb::{FirstAscentBitClimb, FlipNeighbourhood, SteepestAscentBitClimb};
 
 use heur_core::solution::Evaluated;
 
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    eval::Eval,
+    op::{Operator, stop::Stop},
+    solution::Solution,
+};
+
+// NOTE: This is essentially a `RepeatUntil` that owns the solution it operates on, so that a run can be paused, written
+//       out using any `serde` format (such as `serde_json::to_writer`), and resumed later. The operator and stop condition
+//       are stored along with the solution so that their state (such as iteration counts and RNG state) is preserved.
+//       Evaluators are not part of the checkpoint, so any state they hold (such as evaluation counts) must be saved
+//       separately. Neither `StdRng` nor `ThreadRng` can be serialized, so operators that should be checkpointed need an
+//       RNG that can (such as `rand_chacha::ChaCha8Rng` with its `serde` feature).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[must_use]
+pub struct Checkpoint<S, T, F> {
+    pub solution: S,
+    pub op: T,
+    pub stop: F,
+}
+
+impl<S, T, F> Checkpoint<S, T, F> {
+    pub fn new(solution: S, op: T, stop: F) -> Self {
+        Self { solution, op, stop }
+    }
+
+    pub fn into_solution(self) -> S {
+        self.solution
+    }
+
+    // NOTE: The pause condition is always checked before the stop condition, and the stop condition is not checked at all
+    //       if the run is paused. This ensures that pausing and resuming a run does not affect the stop condition, so a
+    //       resumed run continues exactly like an uninterrupted one would (provided all RNGs are seeded and serialized).
+    //       Returns `true` if the run has finished, or `false` if it was paused.
+    pub fn resume<P, E, C>(
+        &mut self,
+        problem: &P,
+        eval: &mut E,
+        mut pause: C,
+    ) -> Result<bool, T::Error>
+    where
+        S: Solution,
+        T: Operator<P, S, E, Output = ()>,
+        F: Stop<P, S, E>,
+        C: Stop<P, S, E>,
+        E: Eval<P, S::Individual>,
+    {
+        loop {
+            if pause.stop(&self.solution, problem, eval) {
+                return Ok(false);
+            }
+            if self.stop.stop(&self.solution, problem, eval) {
+                return Ok(true);
+            }
+            self.op.apply(&mut self.solution, problem, eval, ())?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    use crate::{eval, op::stop::Iterations, solution::Individual};
+
+    use super::*;
+
+    // NOTE: The RNG is part of the operator, so its state is written out and restored along with the checkpoint.
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct RandomWalk<R>(R);
+
+    impl<P, E, R> Operator<P, Individual<i64>, E> for RandomWalk<R>
+    where
+        E: Eval<P, i64>,
+        R: Rng,
+    {
+        type Output = ();
+
+        type Error = Infallible;
+
+        fn apply(
+            &mut self,
+            solution: &mut Individual<i64>,
+            _problem: &P,
+            _eval: &mut E,
+            _input: (),
+        ) -> Result<Self::Output, Self::Error> {
+            **solution += self.0.random_range(-50..50);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn resumed_run_equals_uninterrupted_run() {
+        let mut eval = eval::from_fn(|solution: &i64, _: &()| *solution);
+        let rng = ChaCha8Rng::seed_from_u64(42);
+        let checkpoint = Checkpoint::new(Individual::new(0), RandomWalk(rng), Iterations::new(10));
+
+        let mut uninterrupted = checkpoint.clone();
+        let finished = uninterrupted.resume(&(), &mut eval, Iterations::new(usize::MAX));
+        assert_eq!(finished, Ok(true));
+
+        let mut paused = checkpoint;
+        let finished = paused.resume(&(), &mut eval, Iterations::new(4));
+        assert_eq!(finished, Ok(false));
+
+        let json = serde_json::to_string(&paused).unwrap();
+        let mut resumed: Checkpoint<Individual<i64>, RandomWalk<ChaCha8Rng>, Iterations> =
+            serde_json::from_str(&json).unwrap();
+        let finished = resumed.resume(&(), &mut eval, Iterations::new(usize::MAX));
+        assert_eq!(finished, Ok(true));
+
+        assert_eq!(resumed, uninterrupted);
+        assert_ne!(*resumed.solution, 0);
+    }
+}
@@ -1,12 +1,57 @@
+#[cfg(feature = "std")]
+use core::hash::Hash;
+
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
+#[cfg(feature = "rayon")]
+use alloc::vec::Vec;
+
+use crate::{
+    observe::{Event, Observer},
+    solution::Move,
+};
+
+#[cfg(feature = "rayon")]
+use rayon::{current_num_threads, prelude::*};
+
 mod cached;
 pub use cached::Cached;
 
+#[cfg(feature = "std")]
+mod sync_cached;
+#[cfg(feature = "std")]
+pub use sync_cached::SyncCached;
+
+#[cfg(feature = "std")]
+mod memoized;
+#[cfg(feature = "std")]
+pub use memoized::Memoized;
+
 mod from_fn;
 pub use from_fn::FromFn;
 
+mod direction;
+pub use direction::{Direction, Ranked};
+
+mod directed;
+pub use directed::{Maximize, Minimize};
+
+mod counted;
+pub use counted::Counted;
+
+mod to_f64;
+pub use to_f64::ToF64;
+
+mod observed;
+pub use observed::Observed;
+
+mod with_delta;
+pub use with_delta::WithDelta;
+
+mod full;
+pub(crate) use full::Full;
+
 // NOTE: We could have written `S: Solution` and accepted a `&S::Individual` in `eval`, but this would needlessly worsen
 //       type inference. It also makes more sense to tie `Eval<P, S>` to the solution (individual) being evaluated directly,
 //       rather than the container type (`Individual<T>` or some population type). Furthermore, that would require us to impl
@@ -18,6 +63,30 @@ pub trait Eval<P, S> {
     #[must_use]
     fn eval(&mut self, solution: &S, problem: &P) -> Self::Objective;
 
+    // NOTE: Objective values are maximised by default so that existing evaluators keep their behaviour. Use `minimize`
+    //       instead of negating objective values when minimising.
+    #[must_use]
+    fn direction(&self) -> Direction {
+        Direction::Maximize
+    }
+
+    // NOTE: This is called by caching evaluators (such as `Cached`) whenever they skip evaluating a solution because its
+    //       objective value is already known, so that wrappers like `Counted` can keep track of cache hits. It does nothing
+    //       by default.
+    fn cache_hit(&mut self) {}
+
+    // NOTE: Caching evaluators return whether evaluating the given solution would be a cache hit, so that wrappers around
+    //       them (like `Counted`) can tell cache hits apart from evaluations. Other wrappers should forward it.
+    #[must_use]
+    fn is_cached(&self, _solution: &S, _problem: &P) -> bool {
+        false
+    }
+
+    // NOTE: Operators and combinators report what they are doing by calling this with an `Event`, which lets wrappers like
+    //       `Observed` pass them on to an `Observer`. It does nothing by default, so the calls are optimised away entirely
+    //       when nothing is observing them.
+    fn observe(&mut self, _event: Event<'_, Self::Objective>) {}
+
     fn cached(self) -> Cached<Self>
     where
         Self: Sized,
@@ -25,6 +94,68 @@ pub trait Eval<P, S> {
     {
         Cached(self)
     }
+
+    fn with_delta<F>(self, f: F) -> WithDelta<Self, F>
+    where
+        Self: Sized,
+    {
+        WithDelta { eval: self, f }
+    }
+
+    #[cfg(feature = "std")]
+    fn sync_cached(self) -> SyncCached<Self>
+    where
+        Self: Sized,
+        Self::Objective: Clone,
+    {
+        SyncCached(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn memoized(self, capacity: usize) -> Memoized<Self, S, Self::Objective>
+    where
+        Self: Sized,
+        Self::Objective: Clone,
+        S: Hash + Eq + Clone,
+    {
+        Memoized::new(self, capacity)
+    }
+
+    fn maximize(self) -> Maximize<Self>
+    where
+        Self: Sized,
+    {
+        Maximize(self)
+    }
+
+    fn minimize(self) -> Minimize<Self>
+    where
+        Self: Sized,
+    {
+        Minimize(self)
+    }
+
+    fn counted(self) -> Counted<Self>
+    where
+        Self: Sized,
+    {
+        Counted {
+            eval: self,
+            evaluations: 0,
+            cache_hits: 0,
+        }
+    }
+
+    fn observed<Ob>(self, observer: Ob) -> Observed<Self, Ob>
+    where
+        Self: Sized,
+        Ob: Observer<Self::Objective>,
+    {
+        Observed {
+            eval: self,
+            observer,
+        }
+    }
 }
 
 impl<T, P, S> Eval<P, S> for &mut T
@@ -36,6 +167,22 @@ where
     fn eval(&mut self, solution: &S, problem: &P) -> Self::Objective {
         T::eval(self, solution, problem)
     }
+
+    fn direction(&self) -> Direction {
+        T::direction(self)
+    }
+
+    fn cache_hit(&mut self) {
+        T::cache_hit(self)
+    }
+
+    fn is_cached(&self, solution: &S, problem: &P) -> bool {
+        T::is_cached(self, solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        T::observe(self, event)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -48,6 +195,22 @@ where
     fn eval(&mut self, solution: &S, problem: &P) -> Self::Objective {
         T::eval(self, solution, problem)
     }
+
+    fn direction(&self) -> Direction {
+        T::direction(self)
+    }
+
+    fn cache_hit(&mut self) {
+        T::cache_hit(self)
+    }
+
+    fn is_cached(&self, solution: &S, problem: &P) -> bool {
+        T::is_cached(self, solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        T::observe(self, event)
+    }
 }
 
 #[cfg(feature = "either")]
@@ -64,6 +227,200 @@ where
             Self::Right(right) => right.eval(solution, problem),
         }
     }
+
+    fn direction(&self) -> Direction {
+        match self {
+            Self::Left(left) => left.direction(),
+            Self::Right(right) => right.direction(),
+        }
+    }
+
+    fn cache_hit(&mut self) {
+        match self {
+            Self::Left(left) => left.cache_hit(),
+            Self::Right(right) => right.cache_hit(),
+        }
+    }
+
+    fn is_cached(&self, solution: &S, problem: &P) -> bool {
+        match self {
+            Self::Left(left) => left.is_cached(solution, problem),
+            Self::Right(right) => right.is_cached(solution, problem),
+        }
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        match self {
+            Self::Left(left) => left.observe(event),
+            Self::Right(right) => right.observe(event),
+        }
+    }
+}
+
+// NOTE: Evaluators that can compute the objective value of a neighbouring solution (i.e. one that is a single `Move` away)
+//       more efficiently than evaluating it from scratch can impl this to let operators take advantage of that. The
+//       provided methods fall back to applying the move, evaluating the solution, and then undoing the move. Search
+//       operators only require this once they are wrapped in `op::search::Incremental`, so evaluators that don't support
+//       incremental evaluation don't need to impl it. Use `Eval::with_delta` to add incremental evaluation to an existing
+//       evaluator.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait Delta<P, S, M>: Eval<P, S>
+where
+    M: Move<S>,
+{
+    // NOTE: `objective` is the objective value of `solution` before applying `mv`. The solution is passed mutably so that
+    //       the move can be applied and undone when falling back to a full evaluation, but it must be left unchanged.
+    #[must_use]
+    fn eval_move(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        let _ = objective;
+        mv.apply(solution);
+        let objective = self.eval(solution, problem);
+        mv.undo(solution);
+        objective
+    }
+
+    // NOTE: This lets caching evaluators (such as `Cached`) update their cached objective values incrementally instead of
+    //       invalidating them. By default, it only applies the move.
+    fn apply_move(&mut self, solution: &mut S, problem: &P, mv: &M) {
+        let _ = problem;
+        mv.apply(solution);
+    }
+}
+
+impl<T, P, S, M> Delta<P, S, M> for &mut T
+where
+    T: Delta<P, S, M> + ?Sized,
+    M: Move<S>,
+{
+    fn eval_move(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        T::eval_move(self, solution, problem, objective, mv)
+    }
+
+    fn apply_move(&mut self, solution: &mut S, problem: &P, mv: &M) {
+        T::apply_move(self, solution, problem, mv)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, P, S, M> Delta<P, S, M> for Box<T>
+where
+    T: Delta<P, S, M> + ?Sized,
+    M: Move<S>,
+{
+    fn eval_move(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        T::eval_move(self, solution, problem, objective, mv)
+    }
+
+    fn apply_move(&mut self, solution: &mut S, problem: &P, mv: &M) {
+        T::apply_move(self, solution, problem, mv)
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L, R, P, S, M> Delta<P, S, M> for either::Either<L, R>
+where
+    L: Delta<P, S, M>,
+    R: Delta<P, S, M, Objective = L::Objective>,
+    M: Move<S>,
+{
+    fn eval_move(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        match self {
+            Self::Left(left) => left.eval_move(solution, problem, objective, mv),
+            Self::Right(right) => right.eval_move(solution, problem, objective, mv),
+        }
+    }
+
+    fn apply_move(&mut self, solution: &mut S, problem: &P, mv: &M) {
+        match self {
+            Self::Left(left) => left.apply_move(solution, problem, mv),
+            Self::Right(right) => right.apply_move(solution, problem, mv),
+        }
+    }
+}
+
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait Counter {
+    #[must_use]
+    fn evaluations(&self) -> usize;
+
+    #[must_use]
+    fn cache_hits(&self) -> usize;
+}
+
+impl<T> Counter for &mut T
+where
+    T: Counter + ?Sized,
+{
+    fn evaluations(&self) -> usize {
+        T::evaluations(self)
+    }
+
+    fn cache_hits(&self) -> usize {
+        T::cache_hits(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Counter for Box<T>
+where
+    T: Counter + ?Sized,
+{
+    fn evaluations(&self) -> usize {
+        T::evaluations(self)
+    }
+
+    fn cache_hits(&self) -> usize {
+        T::cache_hits(self)
+    }
+}
+
+// NOTE: Since `Eval::eval` takes `&mut self`, evaluators cannot be shared between threads. Instead, each thread gets its
+//       own evaluator created using `fork`, which is then merged back into the original using `join` once the thread is
+//       done. This lets stateful evaluators (such as `Counted`) keep their state consistent across threads.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait Fork {
+    #[must_use]
+    fn fork(&self) -> Self;
+
+    fn join(&mut self, other: Self);
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Fork for Box<T>
+where
+    T: Fork,
+{
+    fn fork(&self) -> Self {
+        Box::new(T::fork(self))
+    }
+
+    fn join(&mut self, other: Self) {
+        T::join(self, *other)
+    }
 }
 
 pub fn from_fn<F, P, S, O>(f: F) -> FromFn<F>
@@ -73,3 +430,66 @@ where
 {
     FromFn(f)
 }
+
+#[cfg(feature = "rayon")]
+pub fn par_eval<P, S, E>(solutions: &[S], problem: &P, eval: &mut E) -> Vec<E::Objective>
+where
+    P: Sync,
+    S: Sync,
+    E: Eval<P, S, Objective: Send> + Fork + Send + Sync,
+{
+    // Split the solutions into one chunk per thread, and evaluate each chunk using its own forked evaluator
+    let chunk_size = solutions.len().div_ceil(current_num_threads()).max(1);
+    let forked = &*eval;
+    let chunks = solutions
+        .par_chunks(chunk_size)
+        .map(|chunk| {
+            let mut eval = forked.fork();
+            let objectives = chunk
+                .iter()
+                .map(|solution| eval.eval(solution, problem))
+                .collect::<Vec<_>>();
+            (objectives, eval)
+        })
+        .collect::<Vec<_>>();
+
+    // Merge the forked evaluators back and collect the objective values in their original order
+    let mut objectives = Vec::with_capacity(solutions.len());
+    for (chunk, forked) in chunks {
+        objectives.extend(chunk);
+        eval.join(forked);
+    }
+    objectives
+}
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use rayon::ThreadPoolBuilder;
+
+    use super::*;
+
+    #[test]
+    fn par_eval_matches_sequential_evaluation() {
+        let solutions = (0..25)
+            .map(|solution| solution * 7 % 11)
+            .collect::<Vec<i32>>();
+        let square = |solution: &i32, _: &()| solution * solution;
+        let expected = solutions
+            .iter()
+            .map(|solution| square(solution, &()))
+            .collect::<Vec<_>>();
+
+        for threads in [1, 4] {
+            let pool = ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let mut eval = from_fn(square).counted();
+            let objectives = pool.install(|| par_eval(&solutions, &(), &mut eval));
+            assert_eq!(objectives, expected);
+            assert_eq!(eval.evaluations(), solutions.len());
+        }
+    }
+}
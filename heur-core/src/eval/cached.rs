@@ -1,8 +1,12 @@
-use crate::solution::Evaluated;
+use crate::{
+    observe::Event,
+    solution::{Evaluated, Move},
+};
 
-use super::Eval;
+use super::{Counter, Delta, Direction, Eval, Fork};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct Cached<T>(pub(super) T);
 
@@ -13,6 +17,82 @@ where
     type Objective = T::Objective;
 
     fn eval(&mut self, solution: &Evaluated<S, T::Objective>, problem: &P) -> Self::Objective {
-        solution.objective_or_eval(|solution| self.0.eval(solution, problem))
+        match solution.objective() {
+            Some(objective) => {
+                self.0.cache_hit();
+                objective
+            },
+            None => solution.objective_or_eval(|solution| self.0.eval(solution, problem)),
+        }
+    }
+
+    fn direction(&self) -> Direction {
+        self.0.direction()
+    }
+
+    fn cache_hit(&mut self) {
+        self.0.cache_hit();
+    }
+
+    fn is_cached(&self, solution: &Evaluated<S, T::Objective>, problem: &P) -> bool {
+        solution.objective().is_some() || self.0.is_cached(solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        self.0.observe(event);
+    }
+}
+
+impl<T, P, S, M> Delta<P, Evaluated<S, T::Objective>, M> for Cached<T>
+where
+    T: Delta<P, S, M, Objective: Copy>,
+    M: Move<S> + Move<Evaluated<S, T::Objective>>,
+{
+    fn eval_move(
+        &mut self,
+        solution: &mut Evaluated<S, T::Objective>,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        // NOTE: The solution is left unchanged, so the cached objective value remains valid.
+        self.0
+            .eval_move(solution.solution_mut(), problem, objective, mv)
+    }
+
+    fn apply_move(&mut self, solution: &mut Evaluated<S, T::Objective>, problem: &P, mv: &M) {
+        // Update the cached objective value incrementally if there is one, otherwise there is nothing to keep up to date
+        let objective = solution.objective().map(|objective| {
+            self.0
+                .eval_move(solution.solution_mut(), problem, &objective, mv)
+        });
+        Move::<S>::apply(mv, solution.solution_mut());
+        solution.set_objective(objective);
+    }
+}
+
+impl<T> Fork for Cached<T>
+where
+    T: Fork,
+{
+    fn fork(&self) -> Self {
+        Self(self.0.fork())
+    }
+
+    fn join(&mut self, other: Self) {
+        self.0.join(other.0);
+    }
+}
+
+impl<T> Counter for Cached<T>
+where
+    T: Counter,
+{
+    fn evaluations(&self) -> usize {
+        self.0.evaluations()
+    }
+
+    fn cache_hits(&self) -> usize {
+        self.0.cache_hits()
     }
 }
@@ -0,0 +1,183 @@
+use crate::{observe::Event, solution::Move};
+
+use super::{Counter, Delta, Direction, Eval, Fork};
+
+// NOTE: Solutions that a caching evaluator inside this one has already evaluated are counted as cache hits rather than
+//       evaluations (see `Eval::is_cached`). Cache hits are also counted if this sits inside a caching evaluator instead.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Counted<T> {
+    pub(super) eval: T,
+    pub(super) evaluations: usize,
+    pub(super) cache_hits: usize,
+}
+
+impl<T> Counted<T> {
+    pub fn clear(&mut self) {
+        self.evaluations = 0;
+        self.cache_hits = 0;
+    }
+}
+
+impl<T> AsRef<T> for Counted<T> {
+    fn as_ref(&self) -> &T {
+        &self.eval
+    }
+}
+
+impl<T> AsMut<T> for Counted<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.eval
+    }
+}
+
+impl<T, P, S> Eval<P, S> for Counted<T>
+where
+    T: Eval<P, S>,
+{
+    type Objective = T::Objective;
+
+    fn eval(&mut self, solution: &S, problem: &P) -> Self::Objective {
+        if self.eval.is_cached(solution, problem) {
+            self.cache_hits += 1;
+        } else {
+            self.evaluations += 1;
+        }
+        self.eval.eval(solution, problem)
+    }
+
+    fn direction(&self) -> Direction {
+        self.eval.direction()
+    }
+
+    fn cache_hit(&mut self) {
+        self.cache_hits += 1;
+        self.eval.cache_hit();
+    }
+
+    fn is_cached(&self, solution: &S, problem: &P) -> bool {
+        self.eval.is_cached(solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        self.eval.observe(event);
+    }
+}
+
+impl<T, P, S, M> Delta<P, S, M> for Counted<T>
+where
+    T: Delta<P, S, M>,
+    M: Move<S>,
+{
+    fn eval_move(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        self.evaluations += 1;
+        self.eval.eval_move(solution, problem, objective, mv)
+    }
+
+    fn apply_move(&mut self, solution: &mut S, problem: &P, mv: &M) {
+        self.eval.apply_move(solution, problem, mv);
+    }
+}
+
+impl<T> Counter for Counted<T> {
+    fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    fn cache_hits(&self) -> usize {
+        self.cache_hits
+    }
+}
+
+impl<T> Fork for Counted<T>
+where
+    T: Fork,
+{
+    fn fork(&self) -> Self {
+        Self {
+            eval: self.eval.fork(),
+            evaluations: 0,
+            cache_hits: 0,
+        }
+    }
+
+    fn join(&mut self, other: Self) {
+        self.evaluations += other.evaluations;
+        self.cache_hits += other.cache_hits;
+        self.eval.join(other.eval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        eval::{self, FromFn},
+        solution::Evaluated,
+    };
+
+    use super::*;
+
+    type Double = FromFn<fn(&i32, &()) -> i32>;
+
+    fn double() -> Double {
+        let double: fn(&i32, &()) -> i32 = |solution, _problem| solution * 2;
+        eval::from_fn(double)
+    }
+
+    fn eval_twice_then_mutate<E>(eval: &mut E) -> (usize, usize)
+    where
+        E: Eval<(), Evaluated<i32, i32>, Objective = i32> + Counter,
+    {
+        let mut solution = Evaluated::new(21);
+        assert_eq!(eval.eval(&solution, &()), 42);
+        assert_eq!(eval.eval(&solution, &()), 42);
+        *solution += 1;
+        assert_eq!(eval.eval(&solution, &()), 44);
+        (eval.evaluations(), eval.cache_hits())
+    }
+
+    #[test]
+    fn counts_cache_hits_inside_cache() {
+        let mut eval = double().counted().cached();
+        assert_eq!(eval_twice_then_mutate(&mut eval), (2, 1));
+    }
+
+    #[test]
+    fn counts_cache_hits_outside_cache() {
+        let mut eval = double().cached().maximize().counted();
+        assert_eq!(eval_twice_then_mutate(&mut eval), (2, 1));
+
+        eval.clear();
+        assert_eq!((eval.evaluations(), eval.cache_hits()), (0, 0));
+    }
+
+    #[test]
+    fn counts_every_evaluation_without_cache() {
+        let mut eval = double().counted();
+        for solution in [1, 2, 1] {
+            let _ = eval.eval(&solution, &());
+        }
+        assert_eq!((eval.evaluations(), eval.cache_hits()), (3, 0));
+    }
+
+    #[test]
+    fn join_adds_up_counts() {
+        let mut eval = double().counted();
+        let _ = eval.eval(&1, &());
+
+        let mut fork = eval.fork();
+        assert_eq!(fork.evaluations(), 0);
+        let _ = fork.eval(&2, &());
+        let _ = fork.eval(&3, &());
+
+        eval.join(fork);
+        assert_eq!(eval.evaluations(), 3);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{observe::Event, solution::Move};
+
+use super::{Counter, Delta, Direction, Eval, Fork};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Maximize<T>(pub(super) T);
+
+impl<T> AsRef<T> for Maximize<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsMut<T> for Maximize<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, P, S> Eval<P, S> for Maximize<T>
+where
+    T: Eval<P, S>,
+{
+    type Objective = T::Objective;
+
+    fn eval(&mut self, solution: &S, problem: &P) -> Self::Objective {
+        self.0.eval(solution, problem)
+    }
+
+    fn direction(&self) -> Direction {
+        Direction::Maximize
+    }
+
+    fn cache_hit(&mut self) {
+        self.0.cache_hit();
+    }
+
+    fn is_cached(&self, solution: &S, problem: &P) -> bool {
+        self.0.is_cached(solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        self.0.observe(event);
+    }
+}
+
+impl<T, P, S, M> Delta<P, S, M> for Maximize<T>
+where
+    T: Delta<P, S, M>,
+    M: Move<S>,
+{
+    fn eval_move(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        self.0.eval_move(solution, problem, objective, mv)
+    }
+
+    fn apply_move(&mut self, solution: &mut S, problem: &P, mv: &M) {
+        self.0.apply_move(solution, problem, mv);
+    }
+}
+
+impl<T> Fork for Maximize<T>
+where
+    T: Fork,
+{
+    fn fork(&self) -> Self {
+        Self(self.0.fork())
+    }
+
+    fn join(&mut self, other: Self) {
+        self.0.join(other.0);
+    }
+}
+
+impl<T> Counter for Maximize<T>
+where
+    T: Counter,
+{
+    fn evaluations(&self) -> usize {
+        self.0.evaluations()
+    }
+
+    fn cache_hits(&self) -> usize {
+        self.0.cache_hits()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Minimize<T>(pub(super) T);
+
+impl<T> AsRef<T> for Minimize<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsMut<T> for Minimize<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, P, S> Eval<P, S> for Minimize<T>
+where
+    T: Eval<P, S>,
+{
+    type Objective = T::Objective;
+
+    fn eval(&mut self, solution: &S, problem: &P) -> Self::Objective {
+        self.0.eval(solution, problem)
+    }
+
+    fn direction(&self) -> Direction {
+        Direction::Minimize
+    }
+
+    fn cache_hit(&mut self) {
+        self.0.cache_hit();
+    }
+
+    fn is_cached(&self, solution: &S, problem: &P) -> bool {
+        self.0.is_cached(solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        self.0.observe(event);
+    }
+}
+
+impl<T, P, S, M> Delta<P, S, M> for Minimize<T>
+where
+    T: Delta<P, S, M>,
+    M: Move<S>,
+{
+    fn eval_move(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        self.0.eval_move(solution, problem, objective, mv)
+    }
+
+    fn apply_move(&mut self, solution: &mut S, problem: &P, mv: &M) {
+        self.0.apply_move(solution, problem, mv);
+    }
+}
+
+impl<T> Fork for Minimize<T>
+where
+    T: Fork,
+{
+    fn fork(&self) -> Self {
+        Self(self.0.fork())
+    }
+
+    fn join(&mut self, other: Self) {
+        self.0.join(other.0);
+    }
+}
+
+impl<T> Counter for Minimize<T>
+where
+    T: Counter,
+{
+    fn evaluations(&self) -> usize {
+        self.0.evaluations()
+    }
+
+    fn cache_hits(&self) -> usize {
+        self.0.cache_hits()
+    }
+}
@@ -0,0 +1,103 @@
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    #[default]
+    Maximize,
+    Minimize,
+}
+
+impl Direction {
+    // NOTE: This compares objective values by how good they are rather than by their natural ordering, i.e. `Greater`
+    //       means that `objective` is better than `other` regardless of whether we are maximising or minimising.
+    #[must_use]
+    pub fn compare<O>(self, objective: &O, other: &O) -> Option<Ordering>
+    where
+        O: PartialOrd + ?Sized,
+    {
+        match self {
+            Self::Maximize => objective.partial_cmp(other),
+            Self::Minimize => other.partial_cmp(objective),
+        }
+    }
+
+    pub fn rank<O>(self, objective: O) -> Ranked<O> {
+        Ranked {
+            objective,
+            direction: self,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+#[must_use]
+pub struct Ranked<O> {
+    objective: O,
+    direction: Direction,
+}
+
+impl<O> Ranked<O> {
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn into_inner(this: Self) -> O {
+        this.objective
+    }
+}
+
+impl<O> AsRef<O> for Ranked<O> {
+    fn as_ref(&self) -> &O {
+        &self.objective
+    }
+}
+
+impl<O> Eq for Ranked<O> where O: Eq {}
+
+// NOTE: Only the objective values are compared (both here and in the `PartialOrd` and `Ord` impls below). Comparing two
+//       `Ranked<O>`s with different directions is a logic error, and uses the direction of the left-hand side.
+impl<O> PartialEq for Ranked<O>
+where
+    O: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.objective == other.objective
+    }
+}
+
+impl<O> PartialOrd for Ranked<O>
+where
+    O: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.direction.compare(&self.objective, &other.objective)
+    }
+}
+
+impl<O> Ord for Ranked<O>
+where
+    O: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.direction {
+            Direction::Maximize => self.objective.cmp(&other.objective),
+            Direction::Minimize => other.objective.cmp(&self.objective),
+        }
+    }
+}
+
+impl<O> Hash for Ranked<O>
+where
+    O: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.objective.hash(state);
+    }
+}
@@ -1,6 +1,8 @@
 use core::fmt::{self, Debug, Formatter};
 
-use super::Eval;
+use crate::solution::Move;
+
+use super::{Delta, Eval, Fork};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 #[must_use]
@@ -18,6 +20,25 @@ where
     }
 }
 
+impl<F, P, S, O, M> Delta<P, S, M> for FromFn<F>
+where
+    F: FnMut(&S, &P) -> O,
+    O: PartialOrd,
+    M: Move<S>,
+{
+}
+
+impl<F> Fork for FromFn<F>
+where
+    F: Clone,
+{
+    fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    fn join(&mut self, _other: Self) {}
+}
+
 impl<F> Debug for FromFn<F> {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         formatter.debug_tuple("FromFn").finish_non_exhaustive()
@@ -0,0 +1,42 @@
+use crate::{observe::Event, solution::Move};
+
+use super::{Delta, Direction, Eval};
+
+// NOTE: This evaluates moves by applying them and evaluating the solution from scratch (i.e. using the provided methods of
+//       `Delta`), which lets operators that evaluate moves work with any evaluator. Operators only use `Delta` impls
+//       instead when asked to (see `op::search::Incremental`).
+pub(crate) struct Full<'a, T>(pub(crate) &'a mut T);
+
+impl<T, P, S> Eval<P, S> for Full<'_, T>
+where
+    T: Eval<P, S>,
+{
+    type Objective = T::Objective;
+
+    fn eval(&mut self, solution: &S, problem: &P) -> Self::Objective {
+        self.0.eval(solution, problem)
+    }
+
+    fn direction(&self) -> Direction {
+        self.0.direction()
+    }
+
+    fn cache_hit(&mut self) {
+        self.0.cache_hit();
+    }
+
+    fn is_cached(&self, solution: &S, problem: &P) -> bool {
+        self.0.is_cached(solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        self.0.observe(event);
+    }
+}
+
+impl<T, P, S, M> Delta<P, S, M> for Full<'_, T>
+where
+    T: Eval<P, S>,
+    M: Move<S>,
+{
+}
@@ -0,0 +1,287 @@
+use core::hash::Hash;
+
+use std::{collections::HashMap, sync::Arc, vec::Vec};
+
+use crate::{observe::Event, solution::Move};
+
+use super::{Counter, Delta, Direction, Eval, Fork};
+
+// NOTE: This is a least-recently-used cache implemented as a doubly linked list stored in a `Vec`, with a `HashMap` from
+//       solutions to their index in the list. Solutions are stored in an `Arc` so that the same allocation can be shared
+//       between the map and the list, which lets us remove evicted solutions from the map without cloning them again.
+//
+//       Like `Cached`, this reports cache hits to the evaluator it wraps and to wrappers around it (see `Eval::is_cached`),
+//       so a `Counted` evaluator inside or around it counts hits and misses (see `Counter`).
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Memoized<T, S, O> {
+    pub(super) eval: T,
+    pub(super) capacity: usize,
+    pub(super) indices: HashMap<Arc<S>, usize>,
+    pub(super) entries: Vec<Entry<S, O>>,
+    pub(super) head: usize,
+    pub(super) tail: usize,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Entry<S, O> {
+    solution: Arc<S>,
+    objective: O,
+    prev: usize,
+    next: usize,
+}
+
+const NONE: usize = usize::MAX;
+
+impl<T, S, O> Memoized<T, S, O> {
+    pub(super) fn new(eval: T, capacity: usize) -> Self {
+        Self {
+            eval,
+            capacity,
+            indices: HashMap::new(),
+            entries: Vec::new(),
+            head: NONE,
+            tail: NONE,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.indices.clear();
+        self.entries.clear();
+        self.head = NONE;
+        self.tail = NONE;
+    }
+
+    fn unlink(&mut self, idx: usize) {
+        let Entry { prev, next, .. } = self.entries[idx];
+        match prev {
+            NONE => self.head = next,
+            prev => self.entries[prev].next = next,
+        }
+        match next {
+            NONE => self.tail = prev,
+            next => self.entries[next].prev = prev,
+        }
+    }
+
+    fn push_front(&mut self, idx: usize) {
+        self.entries[idx].prev = NONE;
+        self.entries[idx].next = self.head;
+        match self.head {
+            NONE => self.tail = idx,
+            head => self.entries[head].prev = idx,
+        }
+        self.head = idx;
+    }
+}
+
+impl<T, S, O> AsRef<T> for Memoized<T, S, O> {
+    fn as_ref(&self) -> &T {
+        &self.eval
+    }
+}
+
+impl<T, S, O> AsMut<T> for Memoized<T, S, O> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.eval
+    }
+}
+
+impl<T, P, S> Eval<P, S> for Memoized<T, S, T::Objective>
+where
+    T: Eval<P, S, Objective: Clone>,
+    S: Hash + Eq + Clone,
+{
+    type Objective = T::Objective;
+
+    fn eval(&mut self, solution: &S, problem: &P) -> Self::Objective {
+        // Move the solution to the front of the list if it has already been evaluated
+        if let Some(&idx) = self.indices.get(solution) {
+            self.eval.cache_hit();
+            self.unlink(idx);
+            self.push_front(idx);
+            return self.entries[idx].objective.clone();
+        }
+
+        let objective = self.eval.eval(solution, problem);
+        if self.capacity == 0 {
+            return objective;
+        }
+
+        let entry = Entry {
+            solution: Arc::new(solution.clone()),
+            objective: objective.clone(),
+            prev: NONE,
+            next: NONE,
+        };
+
+        // Evict the least recently used solution if the cache is full, reusing its slot for the new solution
+        let idx = if self.entries.len() < self.capacity {
+            self.entries.push(entry);
+            self.entries.len() - 1
+        } else {
+            let idx = self.tail;
+            self.unlink(idx);
+            self.indices.remove(&self.entries[idx].solution);
+            self.entries[idx] = entry;
+            idx
+        };
+        self.indices
+            .insert(Arc::clone(&self.entries[idx].solution), idx);
+        self.push_front(idx);
+
+        objective
+    }
+
+    fn direction(&self) -> Direction {
+        self.eval.direction()
+    }
+
+    fn cache_hit(&mut self) {
+        self.eval.cache_hit();
+    }
+
+    fn is_cached(&self, solution: &S, problem: &P) -> bool {
+        self.indices.contains_key(solution) || self.eval.is_cached(solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        self.eval.observe(event);
+    }
+}
+
+// NOTE: Neighbouring solutions are evaluated (and memoized) in full, since there is no way to look up a solution that is
+//       a move away from another one without applying the move.
+impl<T, P, S, M> Delta<P, S, M> for Memoized<T, S, T::Objective>
+where
+    T: Eval<P, S, Objective: Clone>,
+    S: Hash + Eq + Clone,
+    M: Move<S>,
+{
+}
+
+// NOTE: Forked evaluators start out with an empty cache, which is thrown away when joining.
+impl<T, S, O> Fork for Memoized<T, S, O>
+where
+    T: Fork,
+{
+    fn fork(&self) -> Self {
+        Self::new(self.eval.fork(), self.capacity)
+    }
+
+    fn join(&mut self, other: Self) {
+        self.eval.join(other.eval);
+    }
+}
+
+impl<T, S, O> Counter for Memoized<T, S, O>
+where
+    T: Counter,
+{
+    fn evaluations(&self) -> usize {
+        self.eval.evaluations()
+    }
+
+    fn cache_hits(&self) -> usize {
+        self.eval.cache_hits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eval::{self, Counted, FromFn};
+
+    use super::*;
+
+    type Identity = Counted<FromFn<fn(&i32, &()) -> i32>>;
+
+    fn memoized(capacity: usize) -> Memoized<Identity, i32, i32> {
+        let identity: fn(&i32, &()) -> i32 = |solution, _problem| *solution;
+        eval::from_fn(identity).counted().memoized(capacity)
+    }
+
+    // NOTE: This lists the cached solutions from the most to the least recently used, checking the links both ways.
+    fn order(eval: &Memoized<Identity, i32, i32>) -> Vec<i32> {
+        let mut order = Vec::new();
+        let mut prev = NONE;
+        let mut idx = eval.head;
+        while idx != NONE {
+            let entry = &eval.entries[idx];
+            assert_eq!(entry.prev, prev);
+            assert_eq!(eval.indices[&entry.solution], idx);
+            order.push(*entry.solution);
+            prev = idx;
+            idx = entry.next;
+        }
+        assert_eq!(eval.tail, prev);
+        assert_eq!(eval.indices.len(), order.len());
+        order
+    }
+
+    #[test]
+    fn evicts_least_recently_used_solution() {
+        let mut eval = memoized(3);
+        for solution in [1, 2, 3, 4] {
+            assert_eq!(eval.eval(&solution, &()), solution);
+        }
+        assert_eq!(order(&eval), [4, 3, 2]);
+        assert_eq!(eval.len(), 3);
+
+        assert_eq!(eval.eval(&5, &()), 5);
+        assert_eq!(order(&eval), [5, 4, 3]);
+        assert_eq!((eval.evaluations(), eval.cache_hits()), (5, 0));
+    }
+
+    #[test]
+    fn hits_refresh_solution() {
+        let mut eval = memoized(3);
+        for solution in [1, 2, 3, 1] {
+            assert_eq!(eval.eval(&solution, &()), solution);
+        }
+        assert_eq!(order(&eval), [1, 3, 2]);
+
+        // NOTE: 2 is now the least recently used solution, even though 1 was evaluated first.
+        assert_eq!(eval.eval(&4, &()), 4);
+        assert_eq!(order(&eval), [4, 1, 3]);
+        assert_eq!(eval.eval(&3, &()), 3);
+        assert_eq!(order(&eval), [3, 4, 1]);
+        assert_eq!((eval.evaluations(), eval.cache_hits()), (4, 2));
+
+        eval.clear();
+        assert!(eval.is_empty());
+        assert_eq!(eval.eval(&3, &()), 3);
+        assert_eq!((eval.evaluations(), eval.cache_hits()), (5, 2));
+    }
+
+    #[test]
+    fn caches_nothing_with_capacity_zero() {
+        let mut eval = memoized(0);
+        for solution in [1, 1, 2, 1] {
+            assert_eq!(eval.eval(&solution, &()), solution);
+        }
+        assert!(order(&eval).is_empty());
+        assert_eq!((eval.evaluations(), eval.cache_hits()), (4, 0));
+    }
+
+    #[test]
+    fn caches_last_solution_with_capacity_one() {
+        let mut eval = memoized(1);
+        for solution in [1, 1, 2, 2, 1] {
+            assert_eq!(eval.eval(&solution, &()), solution);
+        }
+        assert_eq!(order(&eval), [1]);
+        assert_eq!((eval.evaluations(), eval.cache_hits()), (3, 2));
+    }
+}
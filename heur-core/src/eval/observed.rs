@@ -0,0 +1,101 @@
+use crate::{
+    observe::{Event, Observer},
+    solution::Move,
+};
+
+use super::{Counter, Delta, Direction, Eval};
+
+// NOTE: This only passes on the events reported by operators and combinators (see `Eval::observe`). In particular, it does
+//       not report new best objective values itself, since most evaluated solutions (such as the neighbours considered by
+//       a local search) are never actually kept (see `Event::NewBest`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[must_use]
+pub struct Observed<T, Ob> {
+    pub(super) eval: T,
+    pub(super) observer: Ob,
+}
+
+impl<T, Ob> Observed<T, Ob> {
+    pub fn observer(&self) -> &Ob {
+        &self.observer
+    }
+
+    pub fn observer_mut(&mut self) -> &mut Ob {
+        &mut self.observer
+    }
+}
+
+impl<T, Ob> AsRef<T> for Observed<T, Ob> {
+    fn as_ref(&self) -> &T {
+        &self.eval
+    }
+}
+
+impl<T, Ob> AsMut<T> for Observed<T, Ob> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.eval
+    }
+}
+
+impl<T, Ob, P, S> Eval<P, S> for Observed<T, Ob>
+where
+    T: Eval<P, S>,
+    Ob: Observer<T::Objective>,
+{
+    type Objective = T::Objective;
+
+    fn eval(&mut self, solution: &S, problem: &P) -> Self::Objective {
+        self.eval.eval(solution, problem)
+    }
+
+    fn direction(&self) -> Direction {
+        self.eval.direction()
+    }
+
+    fn cache_hit(&mut self) {
+        self.eval.cache_hit();
+    }
+
+    fn is_cached(&self, solution: &S, problem: &P) -> bool {
+        self.eval.is_cached(solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        self.eval.observe(event);
+        self.observer.observe(event);
+    }
+}
+
+impl<T, Ob, P, S, M> Delta<P, S, M> for Observed<T, Ob>
+where
+    T: Delta<P, S, M>,
+    Ob: Observer<T::Objective>,
+    M: Move<S>,
+{
+    fn eval_move(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        self.eval.eval_move(solution, problem, objective, mv)
+    }
+
+    fn apply_move(&mut self, solution: &mut S, problem: &P, mv: &M) {
+        self.eval.apply_move(solution, problem, mv);
+    }
+}
+
+impl<T, Ob> Counter for Observed<T, Ob>
+where
+    T: Counter,
+{
+    fn evaluations(&self) -> usize {
+        self.eval.evaluations()
+    }
+
+    fn cache_hits(&self) -> usize {
+        self.eval.cache_hits()
+    }
+}
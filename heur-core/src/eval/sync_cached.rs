@@ -0,0 +1,140 @@
+use crate::{
+    observe::Event,
+    solution::{Move, SyncEvaluated},
+};
+
+use super::{Counter, Delta, Direction, Eval, Fork};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct SyncCached<T>(pub(super) T);
+
+impl<T, P, S> Eval<P, SyncEvaluated<S, T::Objective>> for SyncCached<T>
+where
+    T: Eval<P, S, Objective: Clone>,
+{
+    type Objective = T::Objective;
+
+    fn eval(&mut self, solution: &SyncEvaluated<S, T::Objective>, problem: &P) -> Self::Objective {
+        match solution.objective() {
+            Some(objective) => {
+                self.0.cache_hit();
+                objective.clone()
+            },
+            None => solution
+                .objective_or_eval(|solution| self.0.eval(solution, problem))
+                .clone(),
+        }
+    }
+
+    fn direction(&self) -> Direction {
+        self.0.direction()
+    }
+
+    fn cache_hit(&mut self) {
+        self.0.cache_hit();
+    }
+
+    fn is_cached(&self, solution: &SyncEvaluated<S, T::Objective>, problem: &P) -> bool {
+        solution.objective().is_some() || self.0.is_cached(solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        self.0.observe(event);
+    }
+}
+
+impl<T, P, S, M> Delta<P, SyncEvaluated<S, T::Objective>, M> for SyncCached<T>
+where
+    T: Delta<P, S, M, Objective: Clone>,
+    M: Move<S> + Move<SyncEvaluated<S, T::Objective>>,
+{
+    fn eval_move(
+        &mut self,
+        solution: &mut SyncEvaluated<S, T::Objective>,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        // NOTE: See the note in the corresponding impl for `Cached<T>`.
+        self.0
+            .eval_move(solution.solution_mut(), problem, objective, mv)
+    }
+
+    fn apply_move(&mut self, solution: &mut SyncEvaluated<S, T::Objective>, problem: &P, mv: &M) {
+        // NOTE: See the note in the corresponding impl for `Cached<T>`.
+        let objective = solution.objective().cloned().map(|objective| {
+            self.0
+                .eval_move(solution.solution_mut(), problem, &objective, mv)
+        });
+        Move::<S>::apply(mv, solution.solution_mut());
+        solution.set_objective(objective);
+    }
+}
+
+impl<T> Fork for SyncCached<T>
+where
+    T: Fork,
+{
+    fn fork(&self) -> Self {
+        Self(self.0.fork())
+    }
+
+    fn join(&mut self, other: Self) {
+        self.0.join(other.0);
+    }
+}
+
+impl<T> Counter for SyncCached<T>
+where
+    T: Counter,
+{
+    fn evaluations(&self) -> usize {
+        self.0.evaluations()
+    }
+
+    fn cache_hits(&self) -> usize {
+        self.0.cache_hits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eval::{self, Counter};
+
+    use super::*;
+
+    #[test]
+    fn counts_cache_hits_until_solution_changes() {
+        let mut eval = eval::from_fn(|solution: &i32, _problem: &()| solution * 2)
+            .counted()
+            .sync_cached();
+        let mut solution = SyncEvaluated::new(21);
+
+        assert_eq!(eval.eval(&solution, &()), 42);
+        assert_eq!(eval.eval(&solution, &()), 42);
+        assert_eq!((eval.evaluations(), eval.cache_hits()), (1, 1));
+
+        *solution += 1;
+        assert_eq!(eval.eval(&solution, &()), 44);
+        assert_eq!((eval.evaluations(), eval.cache_hits()), (2, 1));
+    }
+
+    #[test]
+    fn clones_keep_cached_objective_until_mutated() {
+        let mut eval = eval::from_fn(|solution: &i32, _problem: &()| solution * 2)
+            .counted()
+            .sync_cached();
+        let solution = SyncEvaluated::new(21);
+        let _ = eval.eval(&solution, &());
+
+        // NOTE: A clone keeps the cached objective value, but mutating it only clears its own copy.
+        let mut clone = solution.clone();
+        assert_eq!(eval.eval(&clone, &()), 42);
+        *clone -= 1;
+        assert_eq!(eval.eval(&clone, &()), 40);
+        assert_eq!(solution.objective(), Some(&42));
+        assert_eq!((eval.evaluations(), eval.cache_hits()), (2, 1));
+    }
+}
@@ -0,0 +1,32 @@
+// NOTE: Acceptance criteria like simulated annealing need to know by how much an objective value changed, not just whether
+//       it got better. `Into<f64>` would exclude 64-bit and pointer-sized integers since the conversion can lose precision,
+//       which is fine for these purposes, so we use our own trait instead.
+pub trait ToF64 {
+    #[must_use]
+    fn to_f64(&self) -> f64;
+}
+
+impl<T> ToF64 for &T
+where
+    T: ToF64 + ?Sized,
+{
+    fn to_f64(&self) -> f64 {
+        T::to_f64(self)
+    }
+}
+
+macro_rules! impl_for_primitives {
+    ($($ty:ty),+) => {
+        $(
+            impl ToF64 for $ty {
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )+
+    };
+}
+
+impl_for_primitives!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
@@ -0,0 +1,110 @@
+use core::fmt::{self, Debug, Formatter};
+
+use crate::{observe::Event, solution::Move};
+
+use super::{Counter, Delta, Direction, Eval, Fork};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[must_use]
+pub struct WithDelta<T, F> {
+    pub(super) eval: T,
+    pub(super) f: F,
+}
+
+impl<T, F> AsRef<T> for WithDelta<T, F> {
+    fn as_ref(&self) -> &T {
+        &self.eval
+    }
+}
+
+impl<T, F> AsMut<T> for WithDelta<T, F> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.eval
+    }
+}
+
+impl<T, F, P, S> Eval<P, S> for WithDelta<T, F>
+where
+    T: Eval<P, S>,
+{
+    type Objective = T::Objective;
+
+    fn eval(&mut self, solution: &S, problem: &P) -> Self::Objective {
+        self.eval.eval(solution, problem)
+    }
+
+    fn direction(&self) -> Direction {
+        self.eval.direction()
+    }
+
+    fn cache_hit(&mut self) {
+        self.eval.cache_hit();
+    }
+
+    fn is_cached(&self, solution: &S, problem: &P) -> bool {
+        self.eval.is_cached(solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        self.eval.observe(event);
+    }
+}
+
+impl<T, F, P, S, M> Delta<P, S, M> for WithDelta<T, F>
+where
+    T: Eval<P, S>,
+    F: FnMut(&S, &P, &T::Objective, &M) -> T::Objective,
+    M: Move<S>,
+{
+    fn eval_move(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        (self.f)(solution, problem, objective, mv)
+    }
+}
+
+impl<T, F> Fork for WithDelta<T, F>
+where
+    T: Fork,
+    F: Clone,
+{
+    fn fork(&self) -> Self {
+        Self {
+            eval: self.eval.fork(),
+            f: self.f.clone(),
+        }
+    }
+
+    fn join(&mut self, other: Self) {
+        self.eval.join(other.eval);
+    }
+}
+
+impl<T, F> Counter for WithDelta<T, F>
+where
+    T: Counter,
+{
+    fn evaluations(&self) -> usize {
+        self.eval.evaluations()
+    }
+
+    fn cache_hits(&self) -> usize {
+        self.eval.cache_hits()
+    }
+}
+
+impl<T, F> Debug for WithDelta<T, F>
+where
+    T: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("WithDelta")
+            .field("eval", &self.eval)
+            .finish_non_exhaustive()
+    }
+}
@@ -25,6 +25,21 @@ pub mod eval;
 
 pub mod op;
 
+pub mod observe;
+
+mod multi_start;
+pub use multi_start::MultiStart;
+
+#[cfg(feature = "std")]
+mod report;
+#[cfg(feature = "std")]
+pub use report::{Report, Reporting, RunReport, StopReason};
+
+#[cfg(feature = "serde")]
+mod checkpoint;
+#[cfg(feature = "serde")]
+pub use checkpoint::Checkpoint;
+
 // TODO: Add `#[diagnostic::on_unimplemented]`
 pub trait Optimize<P, S, E>
 where
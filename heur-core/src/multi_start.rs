@@ -0,0 +1,198 @@
+use crate::{
+    Optimize,
+    eval::Eval,
+    op::{
+        self,
+        Reset,
+        stop::{Iterations, Stop},
+    },
+    solution::{Individual, Iter, Population},
+};
+
+// NOTE: Every run uses a new solver created by calling `f` with the index of the run (starting from zero). This way, each
+//       run can use a differently seeded RNG (for example, `StdRng::seed_from_u64(seed + run as u64)`).
+//
+//       The stop condition is checked after every run, and is given the best solution found so far. Note that this means
+//       there is always at least one run.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[must_use]
+pub struct MultiStart<F, C> {
+    f: F,
+    cond: C,
+}
+
+impl<F, C> MultiStart<F, C> {
+    pub fn new(f: F, cond: C) -> Self {
+        Self { f, cond }
+    }
+}
+
+impl<F> MultiStart<F, Iterations> {
+    // NOTE: This performs the first run followed by `restarts` more runs.
+    pub fn restarts(f: F, restarts: usize) -> Self {
+        Self::new(f, Iterations::new(restarts))
+    }
+}
+
+impl<F, T, C, P, S, E> Optimize<P, Individual<S>, E> for MultiStart<F, C>
+where
+    F: FnMut(usize) -> T,
+    T: Optimize<P, Individual<S>, E>,
+    C: Stop<P, Individual<S>, E> + Reset,
+    E: Eval<P, S>,
+{
+    type Error = T::Error;
+
+    fn optimize(&mut self, problem: &P, eval: &mut E) -> Result<Individual<S>, Self::Error> {
+        // NOTE: This lets the same solver be run more than once (see `Reset`).
+        self.reset();
+        let direction = eval.direction();
+
+        let mut best = (self.f)(0).optimize(problem, eval)?;
+        let mut best_objective = eval.eval(&best, problem);
+
+        let mut run = 1;
+        while !self.cond.stop(&best, problem, eval) {
+            let solution = (self.f)(run).optimize(problem, eval)?;
+            let objective = eval.eval(&solution, problem);
+            if direction.rank(&objective) > direction.rank(&best_objective) {
+                best = solution;
+                best_objective = objective;
+            }
+            run += 1;
+        }
+
+        Ok(best)
+    }
+}
+
+impl<F, C> Reset for MultiStart<F, C>
+where
+    C: Reset,
+{
+    fn reset(&mut self) {
+        self.cond.reset();
+    }
+}
+
+// NOTE: Populations are compared by their best individuals.
+impl<F, T, C, P, S, E> Optimize<P, S, E> for MultiStart<F, C>
+where
+    F: FnMut(usize) -> T,
+    T: Optimize<P, S, E>,
+    C: Stop<P, S, E> + Reset,
+    S: Population + for<'a> Iter<'a, Item = S::Individual>,
+    E: Eval<P, S::Individual>,
+{
+    type Error = T::Error;
+
+    fn optimize(&mut self, problem: &P, eval: &mut E) -> Result<S, Self::Error> {
+        // NOTE: See the note above.
+        self.reset();
+        let direction = eval.direction();
+
+        let mut best = (self.f)(0).optimize(problem, eval)?;
+        let mut best_objective =
+            op::best_of(best.iter(), direction, problem, eval).map(|(_, objective)| objective);
+
+        let mut run = 1;
+        while !self.cond.stop(&best, problem, eval) {
+            let population = (self.f)(run).optimize(problem, eval)?;
+            let objective = op::best_of(population.iter(), direction, problem, eval)
+                .map(|(_, objective)| objective);
+            let improved = match (&objective, &best_objective) {
+                (Some(objective), Some(best_objective)) => {
+                    direction.rank(objective) > direction.rank(best_objective)
+                },
+                (Some(_), None) => true,
+                (None, _) => false,
+            };
+            if improved {
+                best = population;
+                best_objective = objective;
+            }
+            run += 1;
+        }
+
+        Ok(best)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use core::{cell::Cell, convert::Infallible};
+
+    use crate::{
+        eval::{self, FromFn},
+        solution::Solution,
+    };
+
+    use super::*;
+
+    type Identity = FromFn<fn(&i32, &()) -> i32>;
+
+    fn identity() -> Identity {
+        let identity: fn(&i32, &()) -> i32 = |solution, _| *solution;
+        eval::from_fn(identity)
+    }
+
+    // NOTE: This returns the same solution every time it is run.
+    struct Fixed<S>(S);
+
+    impl<P, S, E> Optimize<P, S, E> for Fixed<S>
+    where
+        S: Solution + Clone,
+        E: Eval<P, S::Individual>,
+    {
+        type Error = Infallible;
+
+        fn optimize(&mut self, _problem: &P, _eval: &mut E) -> Result<S, Self::Error> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn restarts_runs_solver_once_more_than_restarts() {
+        for restarts in [0, 3] {
+            let runs = Cell::new(0);
+            let mut solver = MultiStart::restarts(
+                |run: usize| {
+                    assert_eq!(run, runs.get());
+                    runs.set(run + 1);
+                    Fixed(Individual::new(0))
+                },
+                restarts,
+            );
+
+            let _: Individual<i32> = solver.optimize(&(), &mut identity()).unwrap();
+            assert_eq!(runs.get(), restarts + 1);
+
+            // NOTE: The stop condition is reset, so running again performs the same number of runs.
+            runs.set(0);
+            let _: Individual<i32> = solver.optimize(&(), &mut identity()).unwrap();
+            assert_eq!(runs.get(), restarts + 1);
+        }
+    }
+
+    #[test]
+    fn returns_best_run_by_direction() {
+        let objectives = [3, 7, 1, 5];
+        let mut solver =
+            MultiStart::restarts(|run: usize| Fixed(Individual::new(objectives[run])), 3);
+        let solution: Individual<i32> = solver.optimize(&(), &mut identity()).unwrap();
+        assert_eq!(*solution, 7);
+        let solution: Individual<i32> = solver.optimize(&(), &mut identity().minimize()).unwrap();
+        assert_eq!(*solution, 1);
+    }
+
+    #[test]
+    fn returns_population_with_best_individual_by_direction() {
+        let populations = [vec![], vec![3, 4], vec![8, 2], vec![9, 5]];
+        let mut solver = MultiStart::restarts(|run: usize| Fixed(populations[run].clone()), 3);
+        let population: Vec<i32> = solver.optimize(&(), &mut identity()).unwrap();
+        assert_eq!(population, [9, 5]);
+        let population: Vec<i32> = solver.optimize(&(), &mut identity().minimize()).unwrap();
+        assert_eq!(population, [8, 2]);
+    }
+}
@@ -0,0 +1,208 @@
+use core::any::type_name;
+
+// NOTE: `OperatorApplied` identifies the operator by its type name (see `core::any::type_name`), and by its index if it
+//       was applied from a list of operators, in which case the type name is that of the list. The steps of a local search
+//       (see `Operator::until_local_optimum`) have their own events, so they cannot be confused with the iterations of the
+//       loop around it.
+//
+//       `NewBest` is only reported for solutions that a solver actually keeps, never for solutions that were merely
+//       evaluated (such as neighbours that a local search decided against). It is reported by whatever keeps track of the
+//       best solution found, which is `Track` (see `Stop::track` and `RepeatUntil::keep_best`) or the solver itself.
+//
+//       `StoppedBy` is reported right before `Stopped` by combinators such as `Or` and `Any`, with the index of the
+//       condition that made them stop. Nested combinators report from the innermost outwards, so the indices of the
+//       outermost combinator come last (see `Stop::stop_with_cause`).
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum Event<'a, O> {
+    IterationStarted {
+        iteration: usize,
+    },
+    IterationFinished {
+        iteration: usize,
+    },
+    NewBest {
+        objective: &'a O,
+    },
+    OperatorApplied {
+        operator: &'static str,
+        index: Option<usize>,
+    },
+    Accepted,
+    Rejected,
+    StoppedBy {
+        index: usize,
+    },
+    Stopped {
+        iterations: usize,
+    },
+    LocalSearchStep {
+        step: usize,
+        improved: bool,
+    },
+    LocalSearchFinished {
+        steps: usize,
+    },
+}
+
+impl<O> Event<'_, O> {
+    pub fn operator_applied<T>(_op: &T) -> Self
+    where
+        T: ?Sized,
+    {
+        Self::OperatorApplied {
+            operator: type_name::<T>(),
+            index: None,
+        }
+    }
+
+    pub fn list_operator_applied<T>(_ops: &T, index: usize) -> Self
+    where
+        T: ?Sized,
+    {
+        Self::OperatorApplied {
+            operator: type_name::<T>(),
+            index: Some(index),
+        }
+    }
+}
+
+// NOTE: These are implemented manually since deriving them would require `O: Clone` and `O: Copy` respectively, even
+//       though events only ever contain references to objective values.
+impl<O> Clone for Event<'_, O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O> Copy for Event<'_, O> {}
+
+// NOTE: There is no blanket impl for `&mut T` since it would overlap with the impl for closures (`&mut F` is `FnMut` when
+//       `F` is). Closures and `&mut` references to closures are covered by the latter, and anything else can be wrapped
+//       in a closure.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait Observer<O> {
+    fn observe(&mut self, event: Event<'_, O>);
+}
+
+impl<O> Observer<O> for () {
+    fn observe(&mut self, _event: Event<'_, O>) {}
+}
+
+impl<F, O> Observer<O> for F
+where
+    F: FnMut(Event<'_, O>),
+{
+    fn observe(&mut self, event: Event<'_, O>) {
+        self(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::convert::Infallible;
+
+    use crate::{
+        Optimize,
+        eval::{self, Eval},
+        op::{
+            self,
+            Hint,
+            Operator,
+            Reset,
+            init,
+            stop::{Iterations, Stop},
+        },
+        solution::Individual,
+    };
+
+    use super::*;
+
+    // NOTE: This looks at a much better neighbour, but only ever moves to the next solution.
+    struct Climb;
+
+    impl<P, E> Operator<P, Individual<i32>, E> for Climb
+    where
+        E: Eval<P, i32>,
+    {
+        type Output = ();
+
+        type Error = Infallible;
+
+        fn apply(
+            &mut self,
+            solution: &mut Individual<i32>,
+            problem: &P,
+            eval: &mut E,
+            _input: (),
+        ) -> Result<Self::Output, Self::Error> {
+            let _ = eval.eval(&Individual::new(**solution + 10), problem);
+            **solution += 1;
+            Ok(())
+        }
+    }
+
+    impl Reset for Climb {
+        fn reset(&mut self) {}
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Recorded {
+        IterationStarted(usize),
+        IterationFinished(usize),
+        NewBest(i32),
+        Stopped(usize),
+    }
+
+    #[derive(Default)]
+    struct Recorder(Vec<Recorded>);
+
+    impl Observer<i32> for Recorder {
+        fn observe(&mut self, event: Event<'_, i32>) {
+            self.0.extend(match event {
+                Event::IterationStarted { iteration } => {
+                    Some(Recorded::IterationStarted(iteration))
+                },
+                Event::IterationFinished { iteration } => {
+                    Some(Recorded::IterationFinished(iteration))
+                },
+                Event::NewBest { objective } => Some(Recorded::NewBest(*objective)),
+                Event::Stopped { iterations } => Some(Recorded::Stopped(iterations)),
+                _ => None,
+            });
+        }
+    }
+
+    type Recording = eval::Observed<eval::FromFn<fn(&i32, &()) -> i32>, Recorder>;
+
+    fn hint<T>(op: T) -> Hint<T, (), Individual<i32>, Recording, ()>
+    where
+        T: Operator<(), Individual<i32>, Recording>,
+    {
+        op::hint(op)
+    }
+
+    #[test]
+    fn climb_reports_iterations_and_kept_solutions_in_order() {
+        let identity: fn(&i32, &()) -> i32 = |solution, _problem| *solution;
+        let mut eval: Recording = eval::from_fn(identity).observed(Recorder::default());
+        let stop = Stop::<(), Individual<i32>, Recording>::track(Iterations::new(2));
+        let mut solver = hint(init::from_individual(0)).then(hint(Climb).repeat_until(stop));
+        assert_eq!(*solver.optimize(&(), &mut eval).unwrap(), 2);
+
+        // NOTE: The neighbours that were looked at (10, 11 and 12) were never kept, so they are not reported.
+        assert_eq!(
+            eval.observer().0,
+            [
+                Recorded::NewBest(0),
+                Recorded::IterationStarted(0),
+                Recorded::IterationFinished(0),
+                Recorded::NewBest(1),
+                Recorded::IterationStarted(1),
+                Recorded::IterationFinished(1),
+                Recorded::NewBest(2),
+                Recorded::Stopped(2),
+            ]
+        );
+    }
+}
@@ -1,5 +1,13 @@
 use core::{convert::Infallible, error::Error, marker::PhantomData};
 
+use rand::{Rng, distr::Distribution};
+
+#[cfg(feature = "alloc")]
+use rand::distr::{
+    uniform::{SampleBorrow, SampleUniform},
+    weighted::{Weight, WeightedIndex},
+};
+
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
@@ -7,8 +15,16 @@ use crate::{eval::Eval, solution::Solution};
 
 use accept::Accept;
 
+use search::Search;
+
 use stop::Stop;
 
+mod reset;
+pub use reset::{DynOperator, Reset};
+
+mod fresh;
+pub use fresh::Fresh;
+
 mod then;
 pub use then::Then;
 
@@ -21,6 +37,9 @@ pub use ignore::Ignore;
 mod map;
 pub use map::{Map, MapErr, TryMap};
 
+mod err_into;
+pub use err_into::ErrInto;
+
 mod once;
 pub use once::Once;
 
@@ -28,7 +47,7 @@ mod accept_if;
 pub use accept_if::AcceptIf;
 
 mod repeat;
-pub use repeat::{Repeat, RepeatUntil};
+pub use repeat::{Repeat, RepeatUntil, UntilLocalOptimum};
 
 mod flatten;
 pub use flatten::{FlatMap, Flatten};
@@ -45,6 +64,24 @@ pub use hint::Hint;
 mod todo;
 pub use todo::Todo;
 
+mod logic;
+pub use logic::{All, And, Any, Not, Or, Xor};
+
+mod keep_best;
+pub(crate) use keep_best::best_of;
+pub use keep_best::{KeepBest, Tracking};
+
+mod list;
+pub use list::{MutateList, OperatorList, SearchList};
+
+mod choose;
+pub use choose::Choose;
+#[cfg(feature = "alloc")]
+pub use choose::ChooseWeightedError;
+
+mod vns;
+pub use vns::{VariableNeighbourhoodDescent, VariableNeighbourhoodSearch};
+
 pub mod init;
 
 pub mod mutate;
@@ -55,6 +92,9 @@ pub mod population;
 
 pub mod accept;
 
+#[cfg(feature = "alloc")]
+pub mod adaptive;
+
 pub mod stop;
 
 // TODO: Add `#[diagnostic::on_unimplemented]` and more combinators
@@ -120,6 +160,18 @@ where
         MapErr { op: self, f }
     }
 
+    fn err_into<Err>(self) -> ErrInto<Self, Err>
+    where
+        Self: Sized,
+        Self::Error: Into<Err>,
+        Err: Error,
+    {
+        ErrInto {
+            op: self,
+            marker: PhantomData,
+        }
+    }
+
     fn try_map<Out, F>(self, f: F) -> TryMap<Self, F>
     where
         Self: Sized,
@@ -132,7 +184,10 @@ where
     where
         Self: Sized,
     {
-        Once(Some(self))
+        Once {
+            op: self,
+            done: false,
+        }
     }
 
     fn accept_if<F>(self, cond: F) -> AcceptIf<Self, F>
@@ -159,6 +214,16 @@ where
         RepeatUntil { op: self, cond }
     }
 
+    fn until_local_optimum(self) -> UntilLocalOptimum<Self>
+    where
+        Self: Search<P, S, E> + Sized,
+    {
+        UntilLocalOptimum {
+            op: self,
+            max_steps: None,
+        }
+    }
+
     fn flatten(self) -> Flatten<Self>
     where
         Self: Sized,
@@ -201,6 +266,18 @@ where
     {
         Box::new(self)
     }
+
+    // NOTE: Like `boxed`, but the boxed operator can still be reset (see `DynOperator`).
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn boxed_reset<'a>(
+        self,
+    ) -> Box<dyn DynOperator<P, S, E, In, Output = Self::Output, Error = Self::Error> + 'a>
+    where
+        Self: Reset + Sized + 'a,
+    {
+        Box::new(self)
+    }
 }
 
 impl<T, P, S, E, In> Operator<P, S, E, In> for &mut T
@@ -337,6 +414,62 @@ where
     }
 }
 
+pub fn choose<L, D, R>(ops: L, dist: D, rng: R) -> Choose<L, D, R>
+where
+    D: Distribution<usize>,
+    R: Rng,
+{
+    Choose { ops, dist, rng }
+}
+
+// NOTE: Like `choose`, but each operator is chosen with a probability proportional to its weight. This fails if there
+//       is not exactly one weight per operator, or if the weights are invalid (see `WeightedIndex::new`). The result is
+//       hinted (see `hint`) since checking the number of operators ties it to the problem, solution and evaluator types.
+#[cfg(feature = "alloc")]
+#[allow(clippy::type_complexity)]
+pub fn choose_weighted<P, S, E, In, L, I, X, R>(
+    ops: L,
+    weights: I,
+    rng: R,
+) -> Result<Hint<Choose<L, WeightedIndex<X>, R>, P, S, E, In>, ChooseWeightedError>
+where
+    L: OperatorList<P, S, E, In>,
+    I: IntoIterator<IntoIter: ExactSizeIterator, Item: SampleBorrow<X>>,
+    X: Weight + SampleUniform + PartialOrd,
+    R: Rng,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    let weights = weights.into_iter();
+    if weights.len() != ops.len() {
+        return Err(ChooseWeightedError::InvalidLength {
+            ops: ops.len(),
+            weights: weights.len(),
+        });
+    }
+    let dist = WeightedIndex::new(weights).map_err(ChooseWeightedError::InvalidWeights)?;
+    Ok(hint(choose(ops, dist, rng)))
+}
+
+pub fn fresh<T>(value: T) -> Fresh<T>
+where
+    T: Clone,
+{
+    Fresh {
+        initial: value.clone(),
+        current: value,
+    }
+}
+
+// NOTE: This wraps any solver so that it returns the best solution it found, rather than the one it ended up with. Loops
+//       can also be wrapped directly (see `RepeatUntil::keep_best`), which only looks at the solutions they go through.
+pub fn keep_best<T, I, O>(solver: T) -> KeepBest<T, I, O> {
+    KeepBest {
+        op: solver,
+        best: None,
+    }
+}
+
 pub fn todo<P, S, E, In, Out, Err>() -> Todo<P, S, E, In, Out, Err>
 where
     S: Solution,
@@ -1,7 +1,12 @@
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
-use crate::{eval::Eval, solution::Solution};
+use crate::{
+    eval::{Direction, Eval},
+    solution::Solution,
+};
+
+use super::{And, Not, Or, Xor};
 
 mod unconditional;
 pub use unconditional::{Always, Never};
@@ -9,7 +14,30 @@ pub use unconditional::{Always, Never};
 mod improving;
 pub use improving::{Improving, NonWorsening};
 
-// TODO: Add `#[diagnostic::on_unimplemented]` and more combinators
+#[cfg(feature = "std")]
+mod annealing;
+#[cfg(feature = "std")]
+pub use annealing::SimulatedAnnealing;
+
+mod threshold;
+pub use threshold::ThresholdAccepting;
+
+mod deluge;
+pub use deluge::GreatDeluge;
+
+mod record;
+pub use record::RecordToRecord;
+
+#[cfg(feature = "alloc")]
+mod late_acceptance;
+#[cfg(feature = "alloc")]
+pub use late_acceptance::LateAcceptanceHillClimbing;
+
+pub use super::logic::{all, and, any, not, or, xor};
+
+pub mod cooling;
+
+// TODO: Add `#[diagnostic::on_unimplemented]`
 pub trait Accept<P, S, E>
 where
     S: Solution,
@@ -17,6 +45,46 @@ where
 {
     #[must_use]
     fn accept(&mut self, solution: &S, prev_solution: &S, problem: &P, eval: &mut E) -> bool;
+
+    fn and<F>(self, cond: F) -> And<Self, F>
+    where
+        Self: Sized,
+        F: Accept<P, S, E>,
+    {
+        And {
+            first: self,
+            second: cond,
+        }
+    }
+
+    fn or<F>(self, cond: F) -> Or<Self, F>
+    where
+        Self: Sized,
+        F: Accept<P, S, E>,
+    {
+        Or {
+            first: self,
+            second: cond,
+        }
+    }
+
+    fn xor<F>(self, cond: F) -> Xor<Self, F>
+    where
+        Self: Sized,
+        F: Accept<P, S, E>,
+    {
+        Xor {
+            first: self,
+            second: cond,
+        }
+    }
+
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not(self)
+    }
 }
 
 impl<T, P, S, E> Accept<P, S, E> for &mut T
@@ -57,3 +125,12 @@ where
         }
     }
 }
+
+// NOTE: This measures the change in objective value as an improvement, so that a positive value is always better regardless
+//       of whether we are maximising or minimising.
+pub(crate) fn improvement(direction: Direction, objective: f64, prev_objective: f64) -> f64 {
+    match direction {
+        Direction::Maximize => objective - prev_objective,
+        Direction::Minimize => prev_objective - objective,
+    }
+}
@@ -0,0 +1,137 @@
+use rand::Rng;
+
+use crate::{
+    eval::{Eval, ToF64},
+    op::Reset,
+    solution::Individual,
+};
+
+use super::{Accept, cooling::CoolingSchedule, improvement};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct SimulatedAnnealing<C, R> {
+    schedule: C,
+    rng: R,
+}
+
+impl<C, R> SimulatedAnnealing<C, R> {
+    pub fn new(schedule: C, rng: R) -> Self {
+        Self { schedule, rng }
+    }
+
+    pub fn schedule(&self) -> &C {
+        &self.schedule
+    }
+
+    pub fn schedule_mut(&mut self) -> &mut C {
+        &mut self.schedule
+    }
+}
+
+impl<C, R> SimulatedAnnealing<C, R>
+where
+    C: CoolingSchedule,
+{
+    #[must_use]
+    pub fn temperature(&self) -> f64 {
+        self.schedule.temperature()
+    }
+
+    pub fn reheat(&mut self) {
+        self.schedule.reheat();
+    }
+}
+
+impl<P, S, E, C, R> Accept<P, Individual<S>, E> for SimulatedAnnealing<C, R>
+where
+    E: Eval<P, S, Objective: ToF64>,
+    C: CoolingSchedule,
+    R: Rng,
+{
+    fn accept(
+        &mut self,
+        solution: &Individual<S>,
+        prev_solution: &Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> bool {
+        let objective = eval.eval(solution, problem).to_f64();
+        let prev_objective = eval.eval(prev_solution, problem).to_f64();
+
+        let delta = improvement(eval.direction(), objective, prev_objective);
+
+        // Always accept non-worsening solutions, and accept worsening ones with a probability that decreases as the
+        // temperature drops
+        let temperature = self.schedule.temperature();
+        let accepted = delta >= 0.0
+            || (temperature > 0.0 && self.rng.random::<f64>() < (delta / temperature).exp());
+
+        self.schedule.cool();
+
+        accepted
+    }
+}
+
+impl<C, R> Reset for SimulatedAnnealing<C, R>
+where
+    C: CoolingSchedule,
+{
+    fn reset(&mut self) {
+        self.schedule.reheat();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{
+        eval,
+        op::accept::cooling::{Geometric, Linear},
+    };
+
+    use super::*;
+
+    #[test]
+    fn accepts_worse_solutions_with_boltzmann_probability() {
+        let mut eval = eval::from_fn(|solution: &i32, _problem: &()| *solution);
+        let mut accept =
+            SimulatedAnnealing::new(Geometric::new(2.0, 1.0), StdRng::seed_from_u64(0));
+        let (solution, prev_solution) = (Individual::new(0), Individual::new(2));
+
+        // NOTE: The objective value gets worse by 2 at a temperature of 2, so this should be accepted with probability 1/e.
+        let accepted = (0..10_000)
+            .filter(|_| accept.accept(&solution, &prev_solution, &(), &mut eval))
+            .count();
+        assert!(accepted.abs_diff(3_679) < 200, "{accepted}");
+    }
+
+    #[test]
+    fn direction_decides_which_solutions_are_worse() {
+        let mut eval = eval::from_fn(|solution: &i32, _problem: &()| *solution).minimize();
+        let mut accept = SimulatedAnnealing::new(Linear::new(0.0, 0.0), StdRng::seed_from_u64(0));
+        let (low, high) = (Individual::new(0), Individual::new(2));
+
+        assert!(accept.accept(&low, &high, &(), &mut eval));
+        assert!(!accept.accept(&high, &low, &(), &mut eval));
+    }
+
+    #[test]
+    fn cools_after_every_decision_and_reheats_on_reset() {
+        let mut eval = eval::from_fn(|solution: &i32, _problem: &()| *solution);
+        let mut accept = SimulatedAnnealing::new(Linear::new(1.0, 0.5), StdRng::seed_from_u64(0));
+        let (low, high) = (Individual::new(0), Individual::new(1_000));
+
+        assert!(accept.accept(&high, &low, &(), &mut eval));
+        assert_eq!(accept.temperature(), 0.5);
+        // NOTE: The probability of accepting this at a temperature of 0.5 is `e^-2000`, which rounds to zero.
+        assert!(!accept.accept(&low, &high, &(), &mut eval));
+        assert_eq!(accept.temperature(), 0.0);
+        assert!(!accept.accept(&low, &high, &(), &mut eval));
+
+        accept.reset();
+        assert_eq!(accept.temperature(), 1.0);
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+mod geometric;
+pub use geometric::Geometric;
+
+mod linear;
+pub use linear::Linear;
+
+#[cfg(feature = "std")]
+mod logarithmic;
+#[cfg(feature = "std")]
+pub use logarithmic::Logarithmic;
+
+mod lundy_mees;
+pub use lundy_mees::LundyMees;
+
+mod reheat;
+pub use reheat::Reheat;
+
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait CoolingSchedule {
+    #[must_use]
+    fn temperature(&self) -> f64;
+
+    fn cool(&mut self);
+
+    fn reheat(&mut self);
+
+    fn reheat_below(self, threshold: f64) -> Reheat<Self>
+    where
+        Self: Sized,
+    {
+        Reheat {
+            schedule: self,
+            threshold,
+        }
+    }
+}
+
+impl<T> CoolingSchedule for &mut T
+where
+    T: CoolingSchedule + ?Sized,
+{
+    fn temperature(&self) -> f64 {
+        T::temperature(self)
+    }
+
+    fn cool(&mut self) {
+        T::cool(self)
+    }
+
+    fn reheat(&mut self) {
+        T::reheat(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> CoolingSchedule for Box<T>
+where
+    T: CoolingSchedule + ?Sized,
+{
+    fn temperature(&self) -> f64 {
+        T::temperature(self)
+    }
+
+    fn cool(&mut self) {
+        T::cool(self)
+    }
+
+    fn reheat(&mut self) {
+        T::reheat(self)
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L, R> CoolingSchedule for either::Either<L, R>
+where
+    L: CoolingSchedule,
+    R: CoolingSchedule,
+{
+    fn temperature(&self) -> f64 {
+        match self {
+            Self::Left(left) => left.temperature(),
+            Self::Right(right) => right.temperature(),
+        }
+    }
+
+    fn cool(&mut self) {
+        match self {
+            Self::Left(left) => left.cool(),
+            Self::Right(right) => right.cool(),
+        }
+    }
+
+    fn reheat(&mut self) {
+        match self {
+            Self::Left(left) => left.reheat(),
+            Self::Right(right) => right.reheat(),
+        }
+    }
+}
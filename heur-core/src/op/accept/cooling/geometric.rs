@@ -0,0 +1,53 @@
+use super::CoolingSchedule;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Geometric {
+    initial: f64,
+    temperature: f64,
+    alpha: f64,
+}
+
+impl Geometric {
+    pub fn new(initial: f64, alpha: f64) -> Self {
+        Self {
+            initial,
+            temperature: initial,
+            alpha,
+        }
+    }
+}
+
+impl CoolingSchedule for Geometric {
+    fn temperature(&self) -> f64 {
+        self.temperature
+    }
+
+    fn cool(&mut self) {
+        self.temperature *= self.alpha;
+    }
+
+    fn reheat(&mut self) {
+        self.temperature = self.initial;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cools_by_factor_and_reheats_to_initial() {
+        let mut schedule = Geometric::new(8.0, 0.5);
+        let mut temperatures = [0.0; 4];
+        for temperature in &mut temperatures {
+            *temperature = schedule.temperature();
+            schedule.cool();
+        }
+        assert_eq!(temperatures, [8.0, 4.0, 2.0, 1.0]);
+
+        schedule.reheat();
+        assert_eq!(schedule.temperature(), 8.0);
+    }
+}
@@ -0,0 +1,54 @@
+use super::CoolingSchedule;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Linear {
+    initial: f64,
+    temperature: f64,
+    step: f64,
+}
+
+impl Linear {
+    pub fn new(initial: f64, step: f64) -> Self {
+        Self {
+            initial,
+            temperature: initial,
+            step,
+        }
+    }
+}
+
+impl CoolingSchedule for Linear {
+    fn temperature(&self) -> f64 {
+        self.temperature
+    }
+
+    fn cool(&mut self) {
+        // NOTE: We clamp the temperature to 0 since negative temperatures make no sense.
+        self.temperature = (self.temperature - self.step).max(0.0);
+    }
+
+    fn reheat(&mut self) {
+        self.temperature = self.initial;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cools_by_step_without_going_below_zero() {
+        let mut schedule = Linear::new(2.0, 0.75);
+        let mut temperatures = [0.0; 5];
+        for temperature in &mut temperatures {
+            *temperature = schedule.temperature();
+            schedule.cool();
+        }
+        assert_eq!(temperatures, [2.0, 1.25, 0.5, 0.0, 0.0]);
+
+        schedule.reheat();
+        assert_eq!(schedule.temperature(), 2.0);
+    }
+}
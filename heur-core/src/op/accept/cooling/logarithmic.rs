@@ -0,0 +1,52 @@
+use core::f64::consts::E;
+
+use super::CoolingSchedule;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Logarithmic {
+    initial: f64,
+    iters: u64,
+}
+
+impl Logarithmic {
+    pub fn new(initial: f64) -> Self {
+        Self { initial, iters: 0 }
+    }
+}
+
+impl CoolingSchedule for Logarithmic {
+    fn temperature(&self) -> f64 {
+        // NOTE: We offset the number of iterations by `e` so that the temperature starts at exactly `initial` and never
+        //       divides by zero.
+        self.initial / (self.iters as f64 + E).ln()
+    }
+
+    fn cool(&mut self) {
+        self.iters = self.iters.saturating_add(1);
+    }
+
+    fn reheat(&mut self) {
+        self.iters = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_at_initial_and_cools_logarithmically() {
+        let mut schedule = Logarithmic::new(10.0);
+        assert_eq!(schedule.temperature(), 10.0);
+
+        for _ in 0..100 {
+            schedule.cool();
+        }
+        assert!((schedule.temperature() - 10.0 / (100.0 + E).ln()).abs() < 1e-12);
+
+        schedule.reheat();
+        assert_eq!(schedule.temperature(), 10.0);
+    }
+}
@@ -0,0 +1,54 @@
+use super::CoolingSchedule;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct LundyMees {
+    initial: f64,
+    temperature: f64,
+    beta: f64,
+}
+
+impl LundyMees {
+    pub fn new(initial: f64, beta: f64) -> Self {
+        Self {
+            initial,
+            temperature: initial,
+            beta,
+        }
+    }
+}
+
+impl CoolingSchedule for LundyMees {
+    fn temperature(&self) -> f64 {
+        self.temperature
+    }
+
+    fn cool(&mut self) {
+        self.temperature /= 1.0 + self.beta * self.temperature;
+    }
+
+    fn reheat(&mut self) {
+        self.temperature = self.initial;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cools_more_slowly_as_temperature_drops() {
+        let mut schedule = LundyMees::new(1.0, 1.0);
+        let mut temperatures = [0.0; 4];
+        for temperature in &mut temperatures {
+            *temperature = schedule.temperature();
+            schedule.cool();
+        }
+        // NOTE: With a beta of 1, the temperature after `n` steps is `1 / (n + 1)`.
+        assert_eq!(temperatures, [1.0, 0.5, 1.0 / 3.0, 0.25]);
+
+        schedule.reheat();
+        assert_eq!(schedule.temperature(), 1.0);
+    }
+}
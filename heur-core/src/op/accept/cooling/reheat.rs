@@ -0,0 +1,59 @@
+use super::CoolingSchedule;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Reheat<C> {
+    pub(super) schedule: C,
+    pub(super) threshold: f64,
+}
+
+impl<C> AsRef<C> for Reheat<C> {
+    fn as_ref(&self) -> &C {
+        &self.schedule
+    }
+}
+
+impl<C> AsMut<C> for Reheat<C> {
+    fn as_mut(&mut self) -> &mut C {
+        &mut self.schedule
+    }
+}
+
+impl<C> CoolingSchedule for Reheat<C>
+where
+    C: CoolingSchedule,
+{
+    fn temperature(&self) -> f64 {
+        self.schedule.temperature()
+    }
+
+    fn cool(&mut self) {
+        self.schedule.cool();
+        if self.schedule.temperature() < self.threshold {
+            self.schedule.reheat();
+        }
+    }
+
+    fn reheat(&mut self) {
+        self.schedule.reheat();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::op::accept::cooling::Geometric;
+
+    use super::*;
+
+    #[test]
+    fn reheats_once_below_threshold() {
+        let mut schedule = Geometric::new(8.0, 0.5).reheat_below(3.0);
+        let mut temperatures = [0.0; 5];
+        for temperature in &mut temperatures {
+            *temperature = schedule.temperature();
+            schedule.cool();
+        }
+        assert_eq!(temperatures, [8.0, 4.0, 8.0, 4.0, 8.0]);
+    }
+}
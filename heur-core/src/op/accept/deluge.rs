@@ -0,0 +1,110 @@
+use crate::{
+    eval::{Direction, Eval, ToF64},
+    op::Reset,
+    solution::Individual,
+};
+
+use super::Accept;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct GreatDeluge {
+    initial: f64,
+    level: f64,
+    rate: f64,
+}
+
+impl GreatDeluge {
+    pub fn new(level: f64, rate: f64) -> Self {
+        Self {
+            initial: level,
+            level,
+            rate,
+        }
+    }
+
+    #[must_use]
+    pub fn level(&self) -> f64 {
+        self.level
+    }
+}
+
+impl<P, S, E> Accept<P, Individual<S>, E> for GreatDeluge
+where
+    E: Eval<P, S, Objective: ToF64>,
+{
+    fn accept(
+        &mut self,
+        solution: &Individual<S>,
+        prev_solution: &Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> bool {
+        let direction = eval.direction();
+        let objective = eval.eval(solution, problem).to_f64();
+        let prev_objective = eval.eval(prev_solution, problem).to_f64();
+
+        // Accept any solution that is either not worse than the previous one or above the water level
+        let accepted = direction.rank(objective) >= direction.rank(prev_objective)
+            || direction.rank(objective) >= direction.rank(self.level);
+
+        // NOTE: The water level rises when maximising and falls when minimising, so it always moves towards better
+        //       objective values.
+        match direction {
+            Direction::Maximize => self.level += self.rate,
+            Direction::Minimize => self.level -= self.rate,
+        }
+
+        accepted
+    }
+}
+
+impl Reset for GreatDeluge {
+    fn reset(&mut self) {
+        self.level = self.initial;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eval;
+
+    use super::*;
+
+    #[test]
+    fn water_level_rises_when_maximising() {
+        let mut max = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+        let mut accept = GreatDeluge::new(5.0, 1.0);
+        let (prev_solution, worse) = (Individual::new(10), Individual::new(6));
+
+        assert!(accept.accept(&worse, &prev_solution, &(), &mut max));
+        assert_eq!(accept.level(), 6.0);
+        assert!(accept.accept(&worse, &prev_solution, &(), &mut max));
+        assert_eq!(accept.level(), 7.0);
+        assert!(!accept.accept(&worse, &prev_solution, &(), &mut max));
+        assert_eq!(accept.level(), 8.0);
+
+        // NOTE: Solutions that are not worse than the previous one are accepted even below the water level.
+        assert!(accept.accept(&worse, &worse, &(), &mut max));
+        assert_eq!(accept.level(), 9.0);
+
+        accept.reset();
+        assert_eq!(accept.level(), 5.0);
+    }
+
+    #[test]
+    fn water_level_falls_when_minimising() {
+        let mut min = eval::from_fn(|solution: &i32, _: &()| *solution).minimize();
+        let mut accept = GreatDeluge::new(15.0, 1.0);
+        let (prev_solution, worse) = (Individual::new(10), Individual::new(14));
+
+        assert!(accept.accept(&worse, &prev_solution, &(), &mut min));
+        assert_eq!(accept.level(), 14.0);
+        assert!(accept.accept(&worse, &prev_solution, &(), &mut min));
+        assert_eq!(accept.level(), 13.0);
+        assert!(!accept.accept(&worse, &prev_solution, &(), &mut min));
+        assert_eq!(accept.level(), 12.0);
+        assert!(accept.accept(&prev_solution, &worse, &(), &mut min));
+    }
+}
@@ -1,8 +1,9 @@
-use crate::{eval::Eval, solution::Individual};
+use crate::{eval::Eval, op::Reset, solution::Individual};
 
 use super::Accept;
 
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct Improving;
 
@@ -23,11 +24,14 @@ where
         problem: &P,
         eval: &mut E,
     ) -> bool {
-        eval.eval(solution, problem) > eval.eval(prev_solution, problem)
+        let direction = eval.direction();
+        direction.rank(eval.eval(solution, problem))
+            > direction.rank(eval.eval(prev_solution, problem))
     }
 }
 
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct NonWorsening;
 
@@ -48,6 +52,49 @@ where
         problem: &P,
         eval: &mut E,
     ) -> bool {
-        eval.eval(solution, problem) >= eval.eval(prev_solution, problem)
+        let direction = eval.direction();
+        direction.rank(eval.eval(solution, problem))
+            >= direction.rank(eval.eval(prev_solution, problem))
+    }
+}
+
+impl Reset for Improving {
+    fn reset(&mut self) {}
+}
+
+impl Reset for NonWorsening {
+    fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eval::{self, Eval};
+
+    use super::*;
+
+    #[test]
+    fn improving_follows_direction() {
+        let mut max = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+        let mut min = eval::from_fn(|solution: &i32, _: &()| *solution).minimize();
+        let (low, high) = (Individual::new(1), Individual::new(2));
+
+        assert!(Improving.accept(&high, &low, &(), &mut max));
+        assert!(!Improving.accept(&low, &high, &(), &mut max));
+        assert!(Improving.accept(&low, &high, &(), &mut min));
+        assert!(!Improving.accept(&high, &low, &(), &mut min));
+        assert!(!Improving.accept(&low, &low, &(), &mut min));
+    }
+
+    #[test]
+    fn non_worsening_follows_direction() {
+        let mut max = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+        let mut min = eval::from_fn(|solution: &i32, _: &()| *solution).minimize();
+        let (low, high) = (Individual::new(1), Individual::new(2));
+
+        assert!(NonWorsening.accept(&high, &low, &(), &mut max));
+        assert!(!NonWorsening.accept(&low, &high, &(), &mut max));
+        assert!(NonWorsening.accept(&low, &high, &(), &mut min));
+        assert!(!NonWorsening.accept(&high, &low, &(), &mut min));
+        assert!(NonWorsening.accept(&low, &low, &(), &mut min));
     }
 }
@@ -0,0 +1,132 @@
+use alloc::vec::Vec;
+
+use crate::{eval::Eval, op::Reset, solution::Individual};
+
+use super::Accept;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct LateAcceptanceHillClimbing<O> {
+    len: usize,
+    // NOTE: This is used as a ring buffer of the last `len` objective values, with `idx` pointing to the oldest one.
+    history: Vec<O>,
+    idx: usize,
+}
+
+impl<O> LateAcceptanceHillClimbing<O> {
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            history: Vec::with_capacity(len),
+            idx: 0,
+        }
+    }
+
+    pub fn history(&self) -> &[O] {
+        &self.history
+    }
+}
+
+impl<P, S, E> Accept<P, Individual<S>, E> for LateAcceptanceHillClimbing<E::Objective>
+where
+    E: Eval<P, S, Objective: Clone>,
+{
+    fn accept(
+        &mut self,
+        solution: &Individual<S>,
+        prev_solution: &Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> bool {
+        let direction = eval.direction();
+        let objective = eval.eval(solution, problem);
+        let prev_objective = eval.eval(prev_solution, problem);
+
+        // NOTE: We only know the objective value of the initial solution once we are first asked to accept something,
+        //       so that's when the history gets filled up.
+        if self.history.is_empty() {
+            self.history.resize(self.len, prev_objective.clone());
+        }
+
+        // Accept any solution that is not worse than either the previous one or the one from `len` iterations ago
+        // NOTE: With an empty history (i.e. when `len` is 0), this behaves exactly like regular hill climbing.
+        let accepted = direction.rank(&objective) >= direction.rank(&prev_objective)
+            || self.history.get(self.idx).is_some_and(|late_objective| {
+                direction.rank(&objective) >= direction.rank(late_objective)
+            });
+
+        // Replace the oldest objective value in the history with the current one
+        let current_objective = if accepted { objective } else { prev_objective };
+        if let Some(late_objective) = self.history.get_mut(self.idx) {
+            *late_objective = current_objective;
+            self.idx = (self.idx + 1) % self.len;
+        }
+
+        accepted
+    }
+}
+
+impl<O> Reset for LateAcceptanceHillClimbing<O> {
+    fn reset(&mut self) {
+        self.history.clear();
+        self.idx = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eval;
+
+    use super::*;
+
+    #[test]
+    fn compares_against_objective_from_len_iterations_ago() {
+        let mut max = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+        let mut accept = LateAcceptanceHillClimbing::new(3);
+        let solution = Individual::new;
+
+        // NOTE: The history is filled with the initial objective value on the first call.
+        assert!(accept.accept(&solution(7), &solution(5), &(), &mut max));
+        assert_eq!(accept.history(), [7, 5, 5]);
+
+        // NOTE: This is worse than the previous solution, but not worse than the one from 3 iterations ago.
+        assert!(accept.accept(&solution(6), &solution(7), &(), &mut max));
+        assert_eq!(accept.history(), [7, 6, 5]);
+
+        // NOTE: Rejected solutions record the previous objective value instead.
+        assert!(!accept.accept(&solution(4), &solution(6), &(), &mut max));
+        assert_eq!(accept.history(), [7, 6, 6]);
+
+        // NOTE: The oldest entry is at the start of the history again after wrapping around.
+        assert!(!accept.accept(&solution(5), &solution(6), &(), &mut max));
+        assert_eq!(accept.history(), [6, 6, 6]);
+        assert!(accept.accept(&solution(6), &solution(6), &(), &mut max));
+
+        accept.reset();
+        assert!(accept.history().is_empty());
+    }
+
+    #[test]
+    fn history_follows_direction() {
+        let mut min = eval::from_fn(|solution: &i32, _: &()| *solution).minimize();
+        let mut accept = LateAcceptanceHillClimbing::new(2);
+        let solution = Individual::new;
+
+        assert!(accept.accept(&solution(3), &solution(5), &(), &mut min));
+        assert!(accept.accept(&solution(4), &solution(3), &(), &mut min));
+        assert!(!accept.accept(&solution(4), &solution(3), &(), &mut min));
+        assert_eq!(accept.history(), [3, 4]);
+    }
+
+    #[test]
+    fn empty_history_is_hill_climbing() {
+        let mut max = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+        let mut accept = LateAcceptanceHillClimbing::new(0);
+        let solution = Individual::new;
+
+        assert!(accept.accept(&solution(6), &solution(5), &(), &mut max));
+        assert!(!accept.accept(&solution(4), &solution(5), &(), &mut max));
+        assert!(accept.history().is_empty());
+    }
+}
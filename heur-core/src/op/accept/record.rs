@@ -0,0 +1,105 @@
+use crate::{
+    eval::{Eval, ToF64},
+    op::Reset,
+    solution::Individual,
+};
+
+use super::{Accept, improvement};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct RecordToRecord {
+    deviation: f64,
+    record: Option<f64>,
+}
+
+impl RecordToRecord {
+    pub fn new(deviation: f64) -> Self {
+        Self {
+            deviation,
+            record: None,
+        }
+    }
+
+    #[must_use]
+    pub fn record(&self) -> Option<f64> {
+        self.record
+    }
+}
+
+impl<P, S, E> Accept<P, Individual<S>, E> for RecordToRecord
+where
+    E: Eval<P, S, Objective: ToF64>,
+{
+    fn accept(
+        &mut self,
+        solution: &Individual<S>,
+        prev_solution: &Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> bool {
+        let direction = eval.direction();
+        let objective = eval.eval(solution, problem).to_f64();
+
+        // NOTE: We only know the objective value of the initial solution once we are first asked to accept something,
+        //       so that's when the record gets initialised.
+        let record = match self.record {
+            Some(record) => record,
+            None => eval.eval(prev_solution, problem).to_f64(),
+        };
+
+        // Accept any solution that is not worse than the best one found so far by more than the allowed deviation
+        let delta = improvement(direction, objective, record);
+        let accepted = delta >= -self.deviation;
+
+        self.record = Some(if delta > 0.0 { objective } else { record });
+
+        accepted
+    }
+}
+
+impl Reset for RecordToRecord {
+    fn reset(&mut self) {
+        self.record = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eval;
+
+    use super::*;
+
+    #[test]
+    fn accepts_solutions_within_deviation_from_record() {
+        let mut max = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+        let mut accept = RecordToRecord::new(2.0);
+        let solution = Individual::new;
+
+        assert!(accept.accept(&solution(9), &solution(10), &(), &mut max));
+        assert_eq!(accept.record(), Some(10.0));
+        assert!(accept.accept(&solution(12), &solution(9), &(), &mut max));
+        assert_eq!(accept.record(), Some(12.0));
+
+        // NOTE: The deviation is measured from the record rather than from the previous solution.
+        assert!(accept.accept(&solution(10), &solution(12), &(), &mut max));
+        assert!(!accept.accept(&solution(9), &solution(10), &(), &mut max));
+        assert_eq!(accept.record(), Some(12.0));
+
+        accept.reset();
+        assert_eq!(accept.record(), None);
+    }
+
+    #[test]
+    fn record_follows_direction() {
+        let mut min = eval::from_fn(|solution: &i32, _: &()| *solution).minimize();
+        let mut accept = RecordToRecord::new(2.0);
+        let solution = Individual::new;
+
+        assert!(accept.accept(&solution(11), &solution(10), &(), &mut min));
+        assert!(accept.accept(&solution(7), &solution(11), &(), &mut min));
+        assert_eq!(accept.record(), Some(7.0));
+        assert!(!accept.accept(&solution(10), &solution(7), &(), &mut min));
+    }
+}
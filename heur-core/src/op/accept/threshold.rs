@@ -0,0 +1,109 @@
+use crate::{
+    eval::{Eval, ToF64},
+    op::Reset,
+    solution::Individual,
+};
+
+use super::{Accept, cooling::CoolingSchedule, improvement};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct ThresholdAccepting<C> {
+    // NOTE: Threshold sequences behave exactly like cooling schedules, so we re-use them instead of defining a separate
+    //       trait. The "temperature" of the schedule is the current threshold.
+    schedule: C,
+}
+
+impl<C> ThresholdAccepting<C> {
+    pub fn new(schedule: C) -> Self {
+        Self { schedule }
+    }
+
+    pub fn schedule(&self) -> &C {
+        &self.schedule
+    }
+
+    pub fn schedule_mut(&mut self) -> &mut C {
+        &mut self.schedule
+    }
+}
+
+impl<C> ThresholdAccepting<C>
+where
+    C: CoolingSchedule,
+{
+    #[must_use]
+    pub fn threshold(&self) -> f64 {
+        self.schedule.temperature()
+    }
+}
+
+impl<P, S, E, C> Accept<P, Individual<S>, E> for ThresholdAccepting<C>
+where
+    E: Eval<P, S, Objective: ToF64>,
+    C: CoolingSchedule,
+{
+    fn accept(
+        &mut self,
+        solution: &Individual<S>,
+        prev_solution: &Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> bool {
+        let objective = eval.eval(solution, problem).to_f64();
+        let prev_objective = eval.eval(prev_solution, problem).to_f64();
+
+        // Accept any solution that is not worse than the previous one by more than the current threshold
+        let delta = improvement(eval.direction(), objective, prev_objective);
+        let accepted = delta >= -self.schedule.temperature();
+
+        self.schedule.cool();
+
+        accepted
+    }
+}
+
+impl<C> Reset for ThresholdAccepting<C>
+where
+    C: CoolingSchedule,
+{
+    fn reset(&mut self) {
+        self.schedule.reheat();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{eval, op::accept::cooling::Linear};
+
+    use super::*;
+
+    #[test]
+    fn accepts_worse_solutions_within_threshold() {
+        let mut max = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+        let mut accept = ThresholdAccepting::new(Linear::new(2.0, 1.0));
+        let (prev_solution, worse) = (Individual::new(10), Individual::new(8));
+
+        assert!(accept.accept(&worse, &prev_solution, &(), &mut max));
+        assert_eq!(accept.threshold(), 1.0);
+        assert!(!accept.accept(&worse, &prev_solution, &(), &mut max));
+        assert_eq!(accept.threshold(), 0.0);
+        assert!(accept.accept(&prev_solution, &worse, &(), &mut max));
+        assert!(accept.accept(&prev_solution, &prev_solution, &(), &mut max));
+
+        accept.reset();
+        assert_eq!(accept.threshold(), 2.0);
+    }
+
+    #[test]
+    fn direction_decides_which_solutions_are_worse() {
+        let mut min = eval::from_fn(|solution: &i32, _: &()| *solution).minimize();
+        let mut accept = ThresholdAccepting::new(Linear::new(1.0, 0.0));
+        let (low, high) = (Individual::new(10), Individual::new(12));
+
+        assert!(accept.accept(&low, &high, &(), &mut min));
+        assert!(!accept.accept(&high, &low, &(), &mut min));
+        assert!(accept.accept(&Individual::new(11), &low, &(), &mut min));
+    }
+}
@@ -1,8 +1,9 @@
-use crate::{eval::Eval, solution::Solution};
+use crate::{eval::Eval, op::Reset, solution::Solution};
 
 use super::Accept;
 
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct Always;
 
@@ -23,6 +24,7 @@ where
 }
 
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct Never;
 
@@ -41,3 +43,11 @@ where
         false
     }
 }
+
+impl Reset for Always {
+    fn reset(&mut self) {}
+}
+
+impl Reset for Never {
+    fn reset(&mut self) {}
+}
@@ -1,8 +1,9 @@
-use crate::{eval::Eval, solution::Solution};
+use crate::{eval::Eval, observe::Event, solution::Solution};
 
-use super::{Operator, accept::Accept, mutate::Mutate, search::Search};
+use super::{Operator, Reset, accept::Accept, mutate::Mutate, search::Search};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct AcceptIf<T, F> {
     pub(super) op: T,
@@ -31,8 +32,10 @@ where
 
         let output = self.op.apply(solution, problem, eval, input)?;
         if self.cond.accept(solution, &prev_solution, problem, eval) {
+            eval.observe(Event::Accepted);
             Ok(Some(output))
         } else {
+            eval.observe(Event::Rejected);
             *solution = prev_solution;
             Ok(None)
         }
@@ -50,7 +53,10 @@ where
         let prev_solution = solution.clone();
 
         self.op.mutate(solution, problem, eval)?;
-        if !self.cond.accept(solution, &prev_solution, problem, eval) {
+        if self.cond.accept(solution, &prev_solution, problem, eval) {
+            eval.observe(Event::Accepted);
+        } else {
+            eval.observe(Event::Rejected);
             *solution = prev_solution;
         }
 
@@ -65,14 +71,28 @@ where
     S: Solution + Clone,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
         let prev_solution = solution.clone();
 
-        self.op.search(solution, problem, eval)?;
-        if !self.cond.accept(solution, &prev_solution, problem, eval) {
+        let improved = self.op.search(solution, problem, eval)?;
+        if self.cond.accept(solution, &prev_solution, problem, eval) {
+            eval.observe(Event::Accepted);
+            Ok(improved)
+        } else {
+            eval.observe(Event::Rejected);
             *solution = prev_solution;
+            Ok(false)
         }
+    }
+}
 
-        Ok(())
+impl<T, F> Reset for AcceptIf<T, F>
+where
+    T: Reset,
+    F: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+        self.cond.reset();
     }
 }
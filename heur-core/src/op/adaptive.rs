@@ -0,0 +1,284 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    eval::{Direction, Eval},
+    solution::{Individual, Iter, Population},
+};
+
+use super::{Operator, Reset, best_of, list::OperatorList};
+
+mod credit;
+pub use credit::{Improvement, Success};
+
+mod matching;
+pub use matching::{AdaptivePursuit, ProbabilityMatching};
+
+#[cfg(feature = "std")]
+mod bandit;
+#[cfg(feature = "std")]
+pub use bandit::{SlidingWindowBandit, Ucb1};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperatorStats {
+    pub applications: usize,
+    // NOTE: An application counts as a success if the operator was given a positive reward for it.
+    pub successes: usize,
+    pub total_reward: f64,
+}
+
+impl OperatorStats {
+    #[must_use]
+    pub fn mean_reward(&self) -> f64 {
+        if self.applications == 0 {
+            0.0
+        } else {
+            self.total_reward / self.applications as f64
+        }
+    }
+
+    #[must_use]
+    pub fn success_rate(&self) -> f64 {
+        if self.applications == 0 {
+            0.0
+        } else {
+            self.successes as f64 / self.applications as f64
+        }
+    }
+}
+
+// NOTE: Credit assignment turns the objective value of a solution before and after applying an operator into a reward for
+//       that operator. Rewards should be non-negative, with larger rewards for larger improvements.
+//
+//       There is no blanket impl for `&T` or `Box<T>` since they would overlap with the impl for closures (see the note on
+//       `Observer`).
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait Credit<O> {
+    #[must_use]
+    fn credit(&self, objective: &O, prev_objective: &O, direction: Direction) -> f64;
+}
+
+impl<F, O> Credit<O> for F
+where
+    F: Fn(&O, &O, Direction) -> f64,
+{
+    fn credit(&self, objective: &O, prev_objective: &O, direction: Direction) -> f64 {
+        self(objective, prev_objective, direction)
+    }
+}
+
+// NOTE: A selection rule decides which operator to apply next, given the statistics of every operator so far (with one
+//       entry per operator), and is then told the reward the chosen operator was given. Rules that keep their own estimates
+//       of how good each operator is should size them using the length of `stats`.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait SelectionRule: Reset {
+    // NOTE: This is never called with empty `stats`.
+    #[must_use]
+    fn select(&mut self, stats: &[OperatorStats]) -> usize;
+
+    fn update(&mut self, index: usize, reward: f64, stats: &[OperatorStats]);
+}
+
+impl<T> SelectionRule for &mut T
+where
+    T: SelectionRule + ?Sized,
+{
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        T::select(self, stats)
+    }
+
+    fn update(&mut self, index: usize, reward: f64, stats: &[OperatorStats]) {
+        T::update(self, index, reward, stats)
+    }
+}
+
+impl<T> SelectionRule for Box<T>
+where
+    T: SelectionRule + ?Sized,
+{
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        T::select(self, stats)
+    }
+
+    fn update(&mut self, index: usize, reward: f64, stats: &[OperatorStats]) {
+        T::update(self, index, reward, stats)
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L, R> SelectionRule for either::Either<L, R>
+where
+    L: SelectionRule,
+    R: SelectionRule,
+{
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        match self {
+            Self::Left(left) => left.select(stats),
+            Self::Right(right) => right.select(stats),
+        }
+    }
+
+    fn update(&mut self, index: usize, reward: f64, stats: &[OperatorStats]) {
+        match self {
+            Self::Left(left) => left.update(index, reward, stats),
+            Self::Right(right) => right.update(index, reward, stats),
+        }
+    }
+}
+
+// NOTE: Every application applies one of the operators in a list (see `OperatorList`), chosen by the selection rule, and
+//       credits it based on the objective value of the solution (or the best individual of a population) before and after.
+//       The solution is therefore evaluated twice per application, which a cached evaluator (see `Eval::cached`) avoids.
+//
+//       Applying this to an empty list of operators panics.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct AdaptiveSelection<L, C, T> {
+    ops: L,
+    credit: C,
+    rule: T,
+    stats: Vec<OperatorStats>,
+}
+
+impl<L, C, T> AdaptiveSelection<L, C, T> {
+    pub fn new(ops: L, credit: C, rule: T) -> Self {
+        Self {
+            ops,
+            credit,
+            rule,
+            stats: Vec::new(),
+        }
+    }
+
+    pub fn ops(&self) -> &L {
+        &self.ops
+    }
+
+    pub fn ops_mut(&mut self) -> &mut L {
+        &mut self.ops
+    }
+
+    pub fn rule(&self) -> &T {
+        &self.rule
+    }
+
+    // NOTE: This is empty until the first application, and has one entry per operator afterwards.
+    pub fn stats(&self) -> &[OperatorStats] {
+        &self.stats
+    }
+
+    fn select(&mut self, len: usize) -> usize
+    where
+        T: SelectionRule,
+    {
+        assert!(len > 0, "cannot select an operator from an empty list");
+        // NOTE: The list of operators may have changed length since the last application.
+        self.stats.resize(len, OperatorStats::default());
+        self.rule.select(&self.stats)
+    }
+
+    fn update<O>(
+        &mut self,
+        index: usize,
+        objective: Option<O>,
+        prev_objective: Option<O>,
+        direction: Direction,
+    ) where
+        C: Credit<O>,
+        T: SelectionRule,
+    {
+        let reward = match (objective, prev_objective) {
+            (Some(objective), Some(prev_objective)) => {
+                self.credit.credit(&objective, &prev_objective, direction)
+            },
+            _ => 0.0,
+        };
+
+        let stats = &mut self.stats[index];
+        stats.applications += 1;
+        stats.total_reward += reward;
+        if reward > 0.0 {
+            stats.successes += 1;
+        }
+        self.rule.update(index, reward, &self.stats);
+    }
+}
+
+impl<L, C, T, P, S, E, In> Operator<P, Individual<S>, E, In> for AdaptiveSelection<L, C, T>
+where
+    L: OperatorList<P, Individual<S>, E, In>,
+    C: Credit<E::Objective>,
+    T: SelectionRule,
+    E: Eval<P, S>,
+{
+    type Output = L::Output;
+
+    type Error = L::Error;
+
+    fn apply(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+        input: In,
+    ) -> Result<Self::Output, Self::Error> {
+        let direction = eval.direction();
+        let idx = self.select(self.ops.len());
+
+        let prev_objective = eval.eval(solution, problem);
+        let output = self.ops.apply_nth(idx, solution, problem, eval, input)?;
+        let objective = eval.eval(solution, problem);
+
+        self.update(idx, Some(objective), Some(prev_objective), direction);
+        Ok(output)
+    }
+}
+
+impl<L, C, T, P, S, E, In> Operator<P, S, E, In> for AdaptiveSelection<L, C, T>
+where
+    L: OperatorList<P, S, E, In>,
+    C: Credit<E::Objective>,
+    T: SelectionRule,
+    S: Population + for<'a> Iter<'a, Item = S::Individual>,
+    E: Eval<P, S::Individual>,
+{
+    type Output = L::Output;
+
+    type Error = L::Error;
+
+    fn apply(
+        &mut self,
+        population: &mut S,
+        problem: &P,
+        eval: &mut E,
+        input: In,
+    ) -> Result<Self::Output, Self::Error> {
+        let direction = eval.direction();
+        let idx = self.select(self.ops.len());
+
+        // NOTE: Populations are credited by the objective value of their best individual, which evaluates every individual
+        //       before and after applying the operator. This costs twice the population size in evaluations per application
+        //       unless the evaluator is cached, in which case only individuals changed by the operator are evaluated again.
+        let prev_objective =
+            best_of(population.iter(), direction, problem, eval).map(|(_, objective)| objective);
+        let output = self.ops.apply_nth(idx, population, problem, eval, input)?;
+        let objective =
+            best_of(population.iter(), direction, problem, eval).map(|(_, objective)| objective);
+
+        self.update(idx, objective, prev_objective, direction);
+        Ok(output)
+    }
+}
+
+impl<L, C, T> Reset for AdaptiveSelection<L, C, T>
+where
+    L: Reset,
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.ops.reset();
+        self.rule.reset();
+        self.stats.clear();
+    }
+}
@@ -0,0 +1,188 @@
+use std::vec::Vec;
+
+use crate::op::Reset;
+
+use super::{OperatorStats, SelectionRule};
+
+// NOTE: This treats the operators as the arms of a multi-armed bandit, choosing the one with the highest upper confidence
+//       bound on its mean reward. Every operator is chosen once before any of them are chosen again. The exploration factor
+//       trades off choosing operators with a high mean reward against ones that have been chosen less often, and should be
+//       scaled to the size of the rewards.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Ucb1 {
+    exploration: f64,
+}
+
+impl Ucb1 {
+    pub fn new(exploration: f64) -> Self {
+        Self { exploration }
+    }
+}
+
+impl SelectionRule for Ucb1 {
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        let arms = stats
+            .iter()
+            .map(|stats| (stats.applications, stats.total_reward));
+        let total = stats.iter().map(|stats| stats.applications).sum();
+        upper_confidence_bound(arms, total, self.exploration)
+    }
+
+    fn update(&mut self, _index: usize, _reward: f64, _stats: &[OperatorStats]) {}
+}
+
+impl Reset for Ucb1 {
+    fn reset(&mut self) {}
+}
+
+// NOTE: Like `Ucb1`, but only the last `window` applications are taken into account, so that the choice of operator can
+//       follow changes in how well they perform over the course of the search.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct SlidingWindowBandit {
+    exploration: f64,
+    window: usize,
+    // NOTE: This is used as a ring buffer of the last `window` applications, with `idx` pointing to the oldest one.
+    history: Vec<(usize, f64)>,
+    idx: usize,
+    applications: Vec<usize>,
+    rewards: Vec<f64>,
+}
+
+impl SlidingWindowBandit {
+    pub fn new(exploration: f64, window: usize) -> Self {
+        assert!(
+            window > 0,
+            "the window must contain at least one application"
+        );
+        Self {
+            exploration,
+            window,
+            history: Vec::with_capacity(window),
+            idx: 0,
+            applications: Vec::new(),
+            rewards: Vec::new(),
+        }
+    }
+
+    pub fn window(&self) -> usize {
+        self.window
+    }
+}
+
+impl SelectionRule for SlidingWindowBandit {
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        // NOTE: The window is started over if the number of operators changes.
+        if self.applications.len() != stats.len() {
+            self.reset();
+            self.applications.resize(stats.len(), 0);
+            self.rewards.resize(stats.len(), 0.0);
+        }
+        let arms = self
+            .applications
+            .iter()
+            .copied()
+            .zip(self.rewards.iter().copied());
+        upper_confidence_bound(arms, self.history.len(), self.exploration)
+    }
+
+    fn update(&mut self, index: usize, reward: f64, _stats: &[OperatorStats]) {
+        // Replace the oldest application once the window is full
+        if self.history.len() < self.window {
+            self.history.push((index, reward));
+        } else {
+            let (oldest, oldest_reward) = self.history[self.idx];
+            self.applications[oldest] -= 1;
+            self.rewards[oldest] -= oldest_reward;
+            self.history[self.idx] = (index, reward);
+            self.idx = (self.idx + 1) % self.window;
+        }
+        self.applications[index] += 1;
+        self.rewards[index] += reward;
+    }
+}
+
+impl Reset for SlidingWindowBandit {
+    fn reset(&mut self) {
+        self.history.clear();
+        self.idx = 0;
+        self.applications.clear();
+        self.rewards.clear();
+    }
+}
+
+// NOTE: Operators that have not been applied yet are always chosen first. Ties are broken in favour of the operator that
+//       comes first.
+fn upper_confidence_bound<I>(arms: I, total: usize, exploration: f64) -> usize
+where
+    I: Iterator<Item = (usize, f64)> + Clone,
+{
+    if let Some(idx) = arms.clone().position(|(applications, _)| applications == 0) {
+        return idx;
+    }
+
+    let ln_total = (total as f64).ln();
+    let mut best = (0, f64::NEG_INFINITY);
+    for (idx, (applications, rewards)) in arms.enumerate() {
+        let applications = applications as f64;
+        let bound = rewards / applications + exploration * (2.0 * ln_total / applications).sqrt();
+        if bound > best.1 {
+            best = (idx, bound);
+        }
+    }
+    best.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(arms: &[(usize, f64)]) -> Vec<OperatorStats> {
+        (arms.iter())
+            .map(|&(applications, total_reward)| OperatorStats {
+                applications,
+                successes: 0,
+                total_reward,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ucb1_chooses_unapplied_operators_first() {
+        let mut rule = Ucb1::new(0.0);
+        assert_eq!(rule.select(&stats(&[(3, 3.0), (0, 0.0), (1, 0.0)])), 1);
+    }
+
+    #[test]
+    fn ucb1_trades_off_mean_reward_against_exploration() {
+        let arms = stats(&[(100, 90.0), (1, 0.5)]);
+        assert_eq!(Ucb1::new(0.0).select(&arms), 0);
+        assert_eq!(Ucb1::new(1.0).select(&arms), 1);
+    }
+
+    #[test]
+    fn sliding_window_forgets_old_rewards() {
+        let stats = [OperatorStats::default(); 2];
+        let mut rule = SlidingWindowBandit::new(0.0, 2);
+
+        assert_eq!(rule.select(&stats), 0);
+        rule.update(0, 1.0, &stats);
+        assert_eq!(rule.select(&stats), 1);
+        rule.update(1, 0.0, &stats);
+        assert_eq!(rule.select(&stats), 0);
+
+        // The first reward of operator 0 drops out of the window, then operator 1 is rewarded
+        rule.update(0, 0.0, &stats);
+        rule.update(1, 1.0, &stats);
+        assert_eq!(rule.select(&stats), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sliding_window_rejects_empty_window() {
+        let _ = SlidingWindowBandit::new(1.0, 0);
+    }
+}
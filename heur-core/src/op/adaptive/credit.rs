@@ -0,0 +1,53 @@
+use crate::{
+    eval::{Direction, ToF64},
+    op::accept::improvement,
+};
+
+use super::Credit;
+
+// NOTE: This gives a reward of 1 whenever the operator strictly improved the solution, and 0 otherwise.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Success;
+
+impl Success {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<O> Credit<O> for Success
+where
+    O: PartialOrd,
+{
+    fn credit(&self, objective: &O, prev_objective: &O, direction: Direction) -> f64 {
+        if direction.rank(objective) > direction.rank(prev_objective) {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+// NOTE: This rewards the operator by how much it improved the objective value, or 0 if it made the solution worse.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Improvement;
+
+impl Improvement {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<O> Credit<O> for Improvement
+where
+    O: ToF64,
+{
+    fn credit(&self, objective: &O, prev_objective: &O, direction: Direction) -> f64 {
+        let delta = improvement(direction, objective.to_f64(), prev_objective.to_f64());
+        delta.max(0.0)
+    }
+}
@@ -0,0 +1,248 @@
+use alloc::vec::Vec;
+
+use rand::Rng;
+
+use crate::op::Reset;
+
+use super::{OperatorStats, SelectionRule};
+
+// NOTE: Every operator has a quality estimate, which moves towards the rewards it is given at the adaptation rate (between 0
+//       and 1). Operators are then chosen with probabilities proportional to their quality, while still choosing every
+//       operator with at least the minimum probability so that none of them are ruled out entirely. The minimum probability
+//       is capped at `1 / n` for `n` operators. All qualities start out at 0, so every operator is equally likely at first.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct ProbabilityMatching<R> {
+    adaptation_rate: f64,
+    min_probability: f64,
+    qualities: Vec<f64>,
+    probabilities: Vec<f64>,
+    rng: R,
+}
+
+impl<R> ProbabilityMatching<R> {
+    pub fn new(adaptation_rate: f64, min_probability: f64, rng: R) -> Self {
+        Self {
+            adaptation_rate,
+            min_probability,
+            qualities: Vec::new(),
+            probabilities: Vec::new(),
+            rng,
+        }
+    }
+
+    pub fn qualities(&self) -> &[f64] {
+        &self.qualities
+    }
+
+    pub fn probabilities(&self) -> &[f64] {
+        &self.probabilities
+    }
+
+    fn update_probabilities(&mut self) {
+        let len = self.qualities.len() as f64;
+        let min_probability = self.min_probability.min(1.0 / len);
+        let total: f64 = self.qualities.iter().sum();
+
+        self.probabilities.clear();
+        self.probabilities
+            .extend(self.qualities.iter().map(|quality| {
+                if total > 0.0 {
+                    min_probability + (1.0 - len * min_probability) * quality / total
+                } else {
+                    1.0 / len
+                }
+            }));
+    }
+}
+
+impl<R> SelectionRule for ProbabilityMatching<R>
+where
+    R: Rng,
+{
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        if self.qualities.len() != stats.len() {
+            self.qualities.resize(stats.len(), 0.0);
+            self.update_probabilities();
+        }
+        roulette(&self.probabilities, &mut self.rng)
+    }
+
+    fn update(&mut self, index: usize, reward: f64, _stats: &[OperatorStats]) {
+        let quality = &mut self.qualities[index];
+        *quality += self.adaptation_rate * (reward - *quality);
+        self.update_probabilities();
+    }
+}
+
+impl<R> Reset for ProbabilityMatching<R> {
+    fn reset(&mut self) {
+        self.qualities.clear();
+        self.probabilities.clear();
+    }
+}
+
+// NOTE: Like `ProbabilityMatching`, but rather than being proportional to their quality, the probability of choosing the
+//       operator with the best quality moves towards a maximum probability at the learning rate (between 0 and 1), while
+//       the probabilities of the other operators move towards the minimum probability. This reacts faster to changes in
+//       which operator is best.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct AdaptivePursuit<R> {
+    adaptation_rate: f64,
+    learning_rate: f64,
+    min_probability: f64,
+    qualities: Vec<f64>,
+    probabilities: Vec<f64>,
+    rng: R,
+}
+
+impl<R> AdaptivePursuit<R> {
+    pub fn new(adaptation_rate: f64, learning_rate: f64, min_probability: f64, rng: R) -> Self {
+        Self {
+            adaptation_rate,
+            learning_rate,
+            min_probability,
+            qualities: Vec::new(),
+            probabilities: Vec::new(),
+            rng,
+        }
+    }
+
+    pub fn qualities(&self) -> &[f64] {
+        &self.qualities
+    }
+
+    pub fn probabilities(&self) -> &[f64] {
+        &self.probabilities
+    }
+}
+
+impl<R> SelectionRule for AdaptivePursuit<R>
+where
+    R: Rng,
+{
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        if self.qualities.len() != stats.len() {
+            let len = stats.len();
+            self.qualities.clear();
+            self.qualities.resize(len, 0.0);
+            self.probabilities.clear();
+            self.probabilities.resize(len, 1.0 / len as f64);
+        }
+        roulette(&self.probabilities, &mut self.rng)
+    }
+
+    fn update(&mut self, index: usize, reward: f64, _stats: &[OperatorStats]) {
+        let quality = &mut self.qualities[index];
+        *quality += self.adaptation_rate * (reward - *quality);
+
+        let len = self.qualities.len() as f64;
+        let min_probability = self.min_probability.min(1.0 / len);
+        let max_probability = 1.0 - (len - 1.0) * min_probability;
+
+        // NOTE: Ties are broken in favour of the operator that comes first.
+        let best = (self.qualities.iter().enumerate()).fold(0, |best, (idx, quality)| {
+            if *quality > self.qualities[best] {
+                idx
+            } else {
+                best
+            }
+        });
+        for (idx, probability) in self.probabilities.iter_mut().enumerate() {
+            let target = if idx == best {
+                max_probability
+            } else {
+                min_probability
+            };
+            *probability += self.learning_rate * (target - *probability);
+        }
+    }
+}
+
+impl<R> Reset for AdaptivePursuit<R> {
+    fn reset(&mut self) {
+        self.qualities.clear();
+        self.probabilities.clear();
+    }
+}
+
+// NOTE: This picks an index with a probability proportional to its weight. The last index is used as a fallback in case
+//       rounding errors make us run past the end.
+fn roulette<R>(weights: &[f64], rng: &mut R) -> usize
+where
+    R: Rng,
+{
+    let total: f64 = weights.iter().sum();
+    let mut target = rng.random::<f64>() * total;
+    for (idx, weight) in weights.iter().enumerate() {
+        if target < *weight {
+            return idx;
+        }
+        target -= weight;
+    }
+    weights.len() - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!(
+                (actual - expected).abs() < 1e-9,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn matching_is_proportional_to_quality() {
+        let stats = [OperatorStats::default(); 2];
+        let mut rule = ProbabilityMatching::new(0.5, 0.1, StdRng::seed_from_u64(0));
+
+        let _ = rule.select(&stats);
+        assert_close(rule.probabilities(), &[0.5, 0.5]);
+
+        rule.update(0, 4.0, &stats);
+        rule.update(1, 2.0, &stats);
+        assert_close(rule.qualities(), &[2.0, 1.0]);
+        assert_close(
+            rule.probabilities(),
+            &[0.1 + 0.8 * 2.0 / 3.0, 0.1 + 0.8 / 3.0],
+        );
+    }
+
+    #[test]
+    fn pursuit_moves_towards_best_operator() {
+        let stats = [OperatorStats::default(); 2];
+        let mut rule = AdaptivePursuit::new(1.0, 0.5, 0.1, StdRng::seed_from_u64(0));
+
+        let _ = rule.select(&stats);
+        assert_close(rule.probabilities(), &[0.5, 0.5]);
+
+        rule.update(1, 1.0, &stats);
+        assert_close(rule.probabilities(), &[0.3, 0.7]);
+        rule.update(1, 1.0, &stats);
+        assert_close(rule.probabilities(), &[0.2, 0.8]);
+    }
+
+    #[test]
+    fn pursuit_chooses_best_operator_most_often() {
+        let stats = [OperatorStats::default(); 2];
+        let mut rule = AdaptivePursuit::new(1.0, 1.0, 0.1, StdRng::seed_from_u64(0));
+
+        let _ = rule.select(&stats);
+        rule.update(1, 1.0, &stats);
+        assert_close(rule.probabilities(), &[0.1, 0.9]);
+
+        let chosen = (0..10_000).filter(|_| rule.select(&stats) == 1).count();
+        assert!(chosen.abs_diff(9_000) < 300, "{chosen}");
+    }
+}
@@ -0,0 +1,244 @@
+#[cfg(feature = "alloc")]
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use rand::{Rng, distr::Distribution};
+
+#[cfg(feature = "alloc")]
+use rand::distr::weighted::Error as WeightError;
+
+use crate::{eval::Eval, solution::Solution};
+
+use super::{
+    Operator,
+    Reset,
+    list::{MutateList, OperatorList, SearchList},
+    mutate::Mutate,
+    search::Search,
+};
+
+// NOTE: This applies one of the operators in a list (see `OperatorList`), chosen at random every time it is applied by
+//       sampling an index from the given distribution. Fixed probabilities can be given as a `WeightedIndex` (or as a
+//       `Uniform` over the indices to choose each operator equally often). Sampling an out-of-bounds index panics, which
+//       `op::choose_weighted` rules out by checking that there is one weight per operator.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Choose<L, D, R> {
+    pub(super) ops: L,
+    pub(super) dist: D,
+    pub(super) rng: R,
+}
+
+impl<L, D, R> Choose<L, D, R> {
+    pub fn ops(&self) -> &L {
+        &self.ops
+    }
+
+    pub fn ops_mut(&mut self) -> &mut L {
+        &mut self.ops
+    }
+
+    // NOTE: This samples the index of the next operator to apply and returns it along with the list, so that other
+    //       operator traits (e.g. `Combine`) can be implemented for `Choose` using their own list traits.
+    pub fn choose(&mut self) -> (usize, &mut L)
+    where
+        D: Distribution<usize>,
+        R: Rng,
+    {
+        (self.dist.sample(&mut self.rng), &mut self.ops)
+    }
+}
+
+impl<L, D, R, P, S, E, In> Operator<P, S, E, In> for Choose<L, D, R>
+where
+    L: OperatorList<P, S, E, In>,
+    D: Distribution<usize>,
+    R: Rng,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    type Output = L::Output;
+
+    type Error = L::Error;
+
+    fn apply(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+        input: In,
+    ) -> Result<Self::Output, Self::Error> {
+        let (idx, ops) = self.choose();
+        ops.apply_nth(idx, solution, problem, eval, input)
+    }
+}
+
+impl<L, D, R, P, S, E> Mutate<P, S, E> for Choose<L, D, R>
+where
+    L: MutateList<P, S, E>,
+    D: Distribution<usize>,
+    R: Rng,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn mutate(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
+        let (idx, ops) = self.choose();
+        ops.mutate_nth(idx, solution, problem, eval)
+    }
+}
+
+impl<L, D, R, P, S, E> Search<P, S, E> for Choose<L, D, R>
+where
+    L: SearchList<P, S, E>,
+    D: Distribution<usize>,
+    R: Rng,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
+        let (idx, ops) = self.choose();
+        ops.search_nth(idx, solution, problem, eval)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ChooseWeightedError {
+    InvalidLength { ops: usize, weights: usize },
+    InvalidWeights(WeightError),
+}
+
+#[cfg(feature = "alloc")]
+impl Display for ChooseWeightedError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { ops, weights } => write!(
+                formatter,
+                "cannot choose between {} operators using {} weights",
+                ops, weights,
+            ),
+            Self::InvalidWeights(_) => write!(formatter, "cannot choose using invalid weights"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Error for ChooseWeightedError {
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidLength { .. } => None,
+            Self::InvalidWeights(error) => Some(error),
+        }
+    }
+}
+
+impl<L, D, R> Reset for Choose<L, D, R>
+where
+    L: Reset,
+{
+    fn reset(&mut self) {
+        self.ops.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use rand::{SeedableRng, distr::Uniform, rngs::StdRng};
+
+    use crate::{
+        eval,
+        op::{self, Hint},
+        solution::Individual,
+    };
+
+    use super::*;
+
+    // NOTE: This overwrites the solution with its index, so the solution tells us which operator was chosen.
+    #[derive(Clone, Copy)]
+    struct Mark(usize);
+
+    impl<P, E> Operator<P, Individual<usize>, E> for Mark
+    where
+        E: Eval<P, usize>,
+    {
+        type Output = ();
+
+        type Error = Infallible;
+
+        fn apply(
+            &mut self,
+            solution: &mut Individual<usize>,
+            _problem: &P,
+            _eval: &mut E,
+            _input: (),
+        ) -> Result<Self::Output, Self::Error> {
+            **solution = self.0;
+            Ok(())
+        }
+    }
+
+    type Identity = eval::FromFn<fn(&usize, &()) -> usize>;
+
+    fn frequencies<T>(mut op: T, applications: usize) -> [usize; 3]
+    where
+        T: Operator<(), Individual<usize>, Identity>,
+    {
+        let mut eval: Identity = eval::from_fn(|solution, _| *solution);
+        let mut solution = Individual::new(0);
+        let mut counts = [0; 3];
+        for _ in 0..applications {
+            op.apply(&mut solution, &(), &mut eval, ()).unwrap();
+            counts[*solution] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn chooses_uniformly() {
+        let dist = Uniform::new(0, 3).unwrap();
+        let counts = frequencies(
+            op::choose([Mark(0), Mark(1), Mark(2)], dist, StdRng::seed_from_u64(0)),
+            30_000,
+        );
+        for count in counts {
+            assert!(count.abs_diff(10_000) < 500, "{counts:?}");
+        }
+    }
+
+    #[test]
+    fn chooses_by_weight() {
+        let rng = StdRng::seed_from_u64(0);
+        let choose =
+            op::choose_weighted([Mark(0), Mark(1), Mark(2)], [6.0, 3.0, 1.0], rng).unwrap();
+        let counts = frequencies(choose, 30_000);
+        for (count, expected) in counts.into_iter().zip([18_000, 9_000, 3_000]) {
+            assert!(count.abs_diff(expected) < 500, "{counts:?}");
+        }
+    }
+
+    #[test]
+    fn same_seed_chooses_the_same_operators() {
+        let choose = |seed| {
+            let rng = StdRng::seed_from_u64(seed);
+            op::choose_weighted([Mark(0), Mark(1), Mark(2)], [1.0, 1.0, 1.0], rng).unwrap()
+        };
+        assert_eq!(frequencies(choose(1), 100), frequencies(choose(1), 100));
+    }
+
+    #[test]
+    fn rejects_wrong_number_of_weights() {
+        let rng = StdRng::seed_from_u64(0);
+        let choose: Result<Hint<_, (), Individual<usize>, Identity>, _> =
+            op::choose_weighted([Mark(0), Mark(1), Mark(2)], [1.0, 1.0], rng);
+        assert_eq!(
+            choose.err(),
+            Some(ChooseWeightedError::InvalidLength { ops: 3, weights: 2 })
+        );
+    }
+}
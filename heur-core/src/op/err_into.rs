@@ -0,0 +1,173 @@
+use core::{
+    error::Error,
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::{eval::Eval, solution::Solution};
+
+use super::{Operator, Reset, init::Init, mutate::Mutate, search::Search};
+
+// NOTE: This converts the errors of an operator using `Into`, which lets operators with different error types be combined
+//       by converting them into a common error type (such as `heur::Error`). Operators that cannot fail (i.e. that have an
+//       error type of `Infallible`) can be converted into any error type that impls `From<Infallible>`.
+#[must_use]
+pub struct ErrInto<T, Err> {
+    pub(super) op: T,
+    pub(super) marker: PhantomData<fn() -> Err>,
+}
+
+impl<T, Err> Debug for ErrInto<T, Err>
+where
+    T: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ErrInto")
+            .field("op", &self.op)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, Err> Default for ErrInto<T, Err>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self {
+            op: T::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, Err> Copy for ErrInto<T, Err> where T: Copy {}
+
+impl<T, Err> Clone for ErrInto<T, Err>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            op: self.op.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<T, Err> Eq for ErrInto<T, Err> where T: Eq {}
+
+impl<T, Err> PartialEq for ErrInto<T, Err>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.op == other.op
+    }
+}
+
+impl<T, Err> Hash for ErrInto<T, Err>
+where
+    T: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.op.hash(state);
+        self.marker.hash(state);
+    }
+}
+
+impl<T, Err> AsRef<T> for ErrInto<T, Err> {
+    fn as_ref(&self) -> &T {
+        &self.op
+    }
+}
+
+impl<T, Err> AsMut<T> for ErrInto<T, Err> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.op
+    }
+}
+
+impl<T, Err, P, S, E, In> Operator<P, S, E, In> for ErrInto<T, Err>
+where
+    T: Operator<P, S, E, In, Error: Into<Err>>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+    Err: Error,
+{
+    type Output = T::Output;
+
+    type Error = Err;
+
+    fn apply(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+        input: In,
+    ) -> Result<Self::Output, Self::Error> {
+        self.op
+            .apply(solution, problem, eval, input)
+            .map_err(Into::into)
+    }
+}
+
+impl<T, Err, P, S, E> Init<P, S, E> for ErrInto<T, Err>
+where
+    T: Init<P, S, E, Error: Into<Err>>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+    Err: Error,
+{
+    fn init(&mut self, problem: &P, eval: &mut E) -> Result<S, Self::Error> {
+        self.op.init(problem, eval).map_err(Into::into)
+    }
+
+    fn init_into(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<(), Self::Error> {
+        self.op
+            .init_into(solution, problem, eval)
+            .map_err(Into::into)
+    }
+}
+
+impl<T, Err, P, S, E> Mutate<P, S, E> for ErrInto<T, Err>
+where
+    T: Mutate<P, S, E, Error: Into<Err>>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+    Err: Error,
+{
+    fn mutate(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
+        self.op.mutate(solution, problem, eval).map_err(Into::into)
+    }
+}
+
+impl<T, Err, P, S, E> Search<P, S, E> for ErrInto<T, Err>
+where
+    T: Search<P, S, E, Error: Into<Err>>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+    Err: Error,
+{
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
+        self.op.search(solution, problem, eval).map_err(Into::into)
+    }
+}
+
+impl<T, Err> Reset for ErrInto<T, Err>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+    }
+}
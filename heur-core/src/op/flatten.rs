@@ -2,9 +2,10 @@ use core::fmt::{self, Debug, Formatter};
 
 use crate::{eval::Eval, solution::Solution};
 
-use super::Operator;
+use super::{Operator, Reset};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct Flatten<T>(pub(crate) T);
 
@@ -74,3 +75,22 @@ where
         op.apply(solution, problem, eval, ())
     }
 }
+
+// NOTE: The operators returned by the inner operator are applied straight away, so there is nothing else to reset.
+impl<T> Reset for Flatten<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl<T, F> Reset for FlatMap<T, F>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{eval::Eval, solution::Solution};
+
+use super::{Operator, Reset, accept::Accept, stop::Stop};
+
+// NOTE: This keeps a copy of the value it was created with, and resetting it restores that copy. This makes values that
+//       update themselves in place without remembering their initial state (such as closures capturing a counter)
+//       resettable.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Fresh<T> {
+    pub(super) initial: T,
+    pub(super) current: T,
+}
+
+impl<T> AsRef<T> for Fresh<T> {
+    fn as_ref(&self) -> &T {
+        &self.current
+    }
+}
+
+impl<T> AsMut<T> for Fresh<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.current
+    }
+}
+
+impl<T, P, S, E, In> Operator<P, S, E, In> for Fresh<T>
+where
+    T: Operator<P, S, E, In>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    type Output = T::Output;
+
+    type Error = T::Error;
+
+    fn apply(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+        input: In,
+    ) -> Result<Self::Output, Self::Error> {
+        self.current.apply(solution, problem, eval, input)
+    }
+}
+
+impl<T, P, S, E> Stop<P, S, E> for Fresh<T>
+where
+    T: Stop<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn stop(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.current.stop(solution, problem, eval)
+    }
+
+    fn stop_with_cause(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.current.stop_with_cause(solution, problem, eval)
+    }
+}
+
+impl<T, P, S, E> Accept<P, S, E> for Fresh<T>
+where
+    T: Accept<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn accept(&mut self, solution: &S, prev_solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.current.accept(solution, prev_solution, problem, eval)
+    }
+}
+
+impl<T> Reset for Fresh<T>
+where
+    T: Clone,
+{
+    fn reset(&mut self) {
+        self.current.clone_from(&self.initial);
+    }
+}
@@ -5,7 +5,7 @@ use core::{
 
 use crate::{eval::Eval, solution::Solution};
 
-use super::Operator;
+use super::{Operator, Reset};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 #[must_use]
@@ -38,3 +38,8 @@ impl<F> Debug for FromFn<F> {
         formatter.debug_tuple("FromFn").finish_non_exhaustive()
     }
 }
+
+// NOTE: Any state captured by the function cannot be reset, so this is a no-op.
+impl<F> Reset for FromFn<F> {
+    fn reset(&mut self) {}
+}
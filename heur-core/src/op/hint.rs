@@ -6,8 +6,18 @@ use core::{
 
 use crate::{eval::Eval, solution::Solution};
 
-use super::{Operator, init::Init, mutate::Mutate, search::Search};
-
+use super::{Operator, Reset, init::Init, mutate::Mutate, search::Search};
+
+// NOTE: The bounds need to be specified manually since `serde` would otherwise require `P`, `S`, `E`, and `In` to be
+//       (de)serializable too, even though they only appear in the `PhantomData`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>"
+    ))
+)]
 #[must_use]
 pub struct Hint<T, P, S, E, In = ()> {
     pub(crate) op: T,
@@ -147,7 +157,16 @@ where
     S: Solution,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
         self.op.search(solution, problem, eval)
     }
 }
+
+impl<T, P, S, E, In> Reset for Hint<T, P, S, E, In>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+    }
+}
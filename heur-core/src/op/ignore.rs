@@ -1,8 +1,9 @@
 use crate::{eval::Eval, solution::Solution};
 
-use super::{Operator, init::Init, mutate::Mutate, search::Search};
+use super::{Operator, Reset, init::Init, mutate::Mutate, search::Search};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct Ignore<T>(pub(super) T);
 
@@ -77,7 +78,16 @@ where
     S: Solution,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
         self.0.search(solution, problem, eval)
     }
 }
+
+impl<T> Reset for Ignore<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{Optimize, eval::Eval, op::Operator, solution::Solution};
+use crate::{
+    Optimize,
+    eval::Eval,
+    op::{Operator, Reset},
+    solution::Solution,
+};
 
 use super::Init;
 
@@ -37,3 +42,12 @@ where
         self.0.optimize(problem, eval)
     }
 }
+
+impl<T> Reset for FromSolver<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
@@ -7,13 +7,14 @@ use core::{
 
 use crate::{
     eval::Eval,
-    op::Operator,
+    op::{Operator, Reset},
     solution::{Individual, Population, Solution},
 };
 
 use super::Init;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct FromIndividual<S>(pub(super) S);
 
@@ -58,6 +59,7 @@ where
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct FromPopulation<S>(pub(super) S);
 
@@ -171,3 +173,15 @@ where
         Ok(S::default())
     }
 }
+
+impl<S> Reset for FromIndividual<S> {
+    fn reset(&mut self) {}
+}
+
+impl<S> Reset for FromPopulation<S> {
+    fn reset(&mut self) {}
+}
+
+impl<S> Reset for FromDefault<S> {
+    fn reset(&mut self) {}
+}
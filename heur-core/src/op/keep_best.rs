@@ -0,0 +1,518 @@
+use core::error::Error;
+
+use crate::{
+    Optimize,
+    eval::{Counter, Delta, Direction, Eval, Fork},
+    observe::Event,
+    solution::{Individual, IterMut, Move, Population},
+};
+
+use super::{Operator, RepeatUntil, Reset, stop::Stop};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct KeepBest<T, I, O> {
+    pub(super) op: T,
+    pub(super) best: Option<(I, O)>,
+}
+
+impl<T, I, O> KeepBest<T, I, O> {
+    pub fn best(&self) -> Option<&I> {
+        self.best.as_ref().map(|(best, _)| best)
+    }
+
+    pub fn best_objective(&self) -> Option<&O> {
+        self.best.as_ref().map(|(_, objective)| objective)
+    }
+
+    pub fn into_best(self) -> Option<(I, O)> {
+        self.best
+    }
+}
+
+impl<T, I, O> AsRef<T> for KeepBest<T, I, O> {
+    fn as_ref(&self) -> &T {
+        &self.op
+    }
+}
+
+impl<T, I, O> AsMut<T> for KeepBest<T, I, O> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.op
+    }
+}
+
+impl<T, F, P, S, E, In> Operator<P, Individual<S>, E, In>
+    for KeepBest<RepeatUntil<T, F>, S, E::Objective>
+where
+    T: Operator<P, Individual<S>, E, In, Output = In>,
+    F: Stop<P, Individual<S>, E>,
+    S: Clone,
+    E: Eval<P, S, Objective: Clone>,
+{
+    type Output = In;
+
+    type Error = T::Error;
+
+    fn apply(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+        input: In,
+    ) -> Result<Self::Output, Self::Error> {
+        // NOTE: The stop condition gets to see every solution the loop goes through, so we wrap it to keep track of the
+        //       best one (see `Track`).
+        let mut repeat = RepeatUntil {
+            op: &mut self.op.op,
+            cond: (&mut self.op.cond).track(),
+        };
+        let output = repeat.apply(solution, problem, eval, input);
+        self.best = repeat.cond.into_best();
+        let output = output?;
+
+        if let Some(best) = &self.best {
+            restore_individual(solution, best, problem, eval);
+        }
+        Ok(output)
+    }
+}
+
+impl<T, F, P, S, E, In> Operator<P, S, E, In>
+    for KeepBest<RepeatUntil<T, F>, S::Individual, E::Objective>
+where
+    T: Operator<P, S, E, In, Output = In>,
+    F: Stop<P, S, E>,
+    S: Population<Individual: Clone> + for<'a> IterMut<'a, Item = S::Individual>,
+    E: Eval<P, S::Individual, Objective: Clone>,
+{
+    type Output = In;
+
+    type Error = T::Error;
+
+    fn apply(
+        &mut self,
+        population: &mut S,
+        problem: &P,
+        eval: &mut E,
+        input: In,
+    ) -> Result<Self::Output, Self::Error> {
+        // NOTE: See the note in the impl above.
+        let mut repeat = RepeatUntil {
+            op: &mut self.op.op,
+            cond: (&mut self.op.cond).track(),
+        };
+        let output = repeat.apply(population, problem, eval, input);
+        self.best = repeat.cond.into_best();
+        let output = output?;
+
+        if let Some(best) = &self.best {
+            restore_population(population, best, problem, eval);
+        }
+        Ok(output)
+    }
+}
+
+// NOTE: A solver does not let us see the solutions it goes through, so instead we wrap its evaluator to keep track of the
+//       best solution it has been asked to evaluate (see `Tracking`). Note that this may be a solution the solver never
+//       actually moved to (such as a neighbour that a tabu search ruled out), since any evaluated solution is a valid one.
+impl<T, P, S, E, Er> Optimize<P, Individual<S>, E> for KeepBest<T, S, E::Objective>
+where
+    T: for<'a> Optimize<P, Individual<S>, Tracking<&'a mut E, S, E::Objective>, Error = Er>,
+    S: Clone,
+    E: Eval<P, S, Objective: Clone>,
+    Er: Error,
+{
+    type Error = Er;
+
+    fn optimize(&mut self, problem: &P, eval: &mut E) -> Result<Individual<S>, Self::Error> {
+        let direction = eval.direction();
+        let mut tracking = Tracking::new(&mut *eval, direction);
+        let solution = self.op.optimize(problem, &mut tracking);
+        self.best = tracking.best;
+        let mut solution = solution?;
+
+        if let Some(best) = &self.best {
+            restore_individual(&mut solution, best, problem, eval);
+        }
+        Ok(solution)
+    }
+}
+
+impl<T, P, S, E, Er> Optimize<P, S, E> for KeepBest<T, S::Individual, E::Objective>
+where
+    T: for<'a> Optimize<P, S, Tracking<&'a mut E, S::Individual, E::Objective>, Error = Er>,
+    S: Population<Individual: Clone> + for<'a> IterMut<'a, Item = S::Individual>,
+    E: Eval<P, S::Individual, Objective: Clone>,
+    Er: Error,
+{
+    type Error = Er;
+
+    fn optimize(&mut self, problem: &P, eval: &mut E) -> Result<S, Self::Error> {
+        let direction = eval.direction();
+        let mut tracking = Tracking::new(&mut *eval, direction);
+        let population = self.op.optimize(problem, &mut tracking);
+        self.best = tracking.best;
+        let mut population = population?;
+
+        if let Some(best) = &self.best {
+            restore_population(&mut population, best, problem, eval);
+        }
+        Ok(population)
+    }
+}
+
+// Replace the final solution with the best one if it is worse
+fn restore_individual<P, S, E>(
+    solution: &mut Individual<S>,
+    best: &(S, E::Objective),
+    problem: &P,
+    eval: &mut E,
+) where
+    S: Clone,
+    E: Eval<P, S>,
+{
+    let (best, best_objective) = best;
+    let direction = eval.direction();
+    let objective = eval.eval(solution, problem);
+    if direction.rank(best_objective) > direction.rank(&objective) {
+        solution.clone_from(Individual::from_ref(best));
+    }
+}
+
+// Replace the worst individual in the final population with the best one if the population is worse
+fn restore_population<P, S, E>(
+    population: &mut S,
+    best: &(S::Individual, E::Objective),
+    problem: &P,
+    eval: &mut E,
+) where
+    S: Population<Individual: Clone> + for<'a> IterMut<'a, Item = S::Individual>,
+    E: Eval<P, S::Individual>,
+{
+    let (best, best_objective) = best;
+    let direction = eval.direction();
+    let population_best = best_of(population.iter(), direction, problem, eval);
+    let improved = population_best
+        .is_some_and(|(_, objective)| direction.rank(best_objective) > direction.rank(&objective));
+    if !improved {
+        return;
+    }
+
+    let worst = population
+        .iter_mut()
+        .map(|solution| {
+            let objective = eval.eval(solution, problem);
+            (solution, objective)
+        })
+        .reduce(|worst, candidate| {
+            if direction.rank(&candidate.1) < direction.rank(&worst.1) {
+                candidate
+            } else {
+                worst
+            }
+        });
+    if let Some((worst, _)) = worst {
+        worst.clone_from(best);
+    }
+}
+
+// NOTE: This is the evaluator `KeepBest` gives to the solver it wraps. It keeps a clone of the best solution it has
+//       evaluated so far, including neighbours evaluated incrementally (see `Delta`).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[must_use]
+pub struct Tracking<T, I, O> {
+    eval: T,
+    // NOTE: This is needed to merge forked evaluators back (see `Fork`), which happens without a problem or solution type
+    //       to ask the evaluator for its direction with.
+    direction: Direction,
+    best: Option<(I, O)>,
+}
+
+impl<T, I, O> Tracking<T, I, O> {
+    fn new(eval: T, direction: Direction) -> Self {
+        Self {
+            eval,
+            direction,
+            best: None,
+        }
+    }
+
+    fn improves(&self, objective: &O) -> bool
+    where
+        O: PartialOrd,
+    {
+        self.best
+            .as_ref()
+            .is_none_or(|(_, best)| self.direction.rank(objective) > self.direction.rank(best))
+    }
+}
+
+impl<T, I, O> AsRef<T> for Tracking<T, I, O> {
+    fn as_ref(&self) -> &T {
+        &self.eval
+    }
+}
+
+impl<T, I, O> AsMut<T> for Tracking<T, I, O> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.eval
+    }
+}
+
+impl<T, P, I> Eval<P, I> for Tracking<T, I, T::Objective>
+where
+    T: Eval<P, I, Objective: Clone>,
+    I: Clone,
+{
+    type Objective = T::Objective;
+
+    fn eval(&mut self, solution: &I, problem: &P) -> Self::Objective {
+        let objective = self.eval.eval(solution, problem);
+        if self.improves(&objective) {
+            self.best = Some((solution.clone(), objective.clone()));
+        }
+        objective
+    }
+
+    fn direction(&self) -> Direction {
+        self.eval.direction()
+    }
+
+    fn cache_hit(&mut self) {
+        self.eval.cache_hit();
+    }
+
+    fn is_cached(&self, solution: &I, problem: &P) -> bool {
+        self.eval.is_cached(solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        self.eval.observe(event);
+    }
+}
+
+impl<T, P, I, M> Delta<P, I, M> for Tracking<T, I, T::Objective>
+where
+    T: Delta<P, I, M, Objective: Clone>,
+    I: Clone,
+    M: Move<I>,
+{
+    fn eval_move(
+        &mut self,
+        solution: &mut I,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        let objective = self.eval.eval_move(solution, problem, objective, mv);
+        if self.improves(&objective) {
+            mv.apply(solution);
+            self.best = Some((solution.clone(), objective.clone()));
+            mv.undo(solution);
+        }
+        objective
+    }
+
+    fn apply_move(&mut self, solution: &mut I, problem: &P, mv: &M) {
+        self.eval.apply_move(solution, problem, mv);
+    }
+}
+
+impl<T, I, O> Counter for Tracking<T, I, O>
+where
+    T: Counter,
+{
+    fn evaluations(&self) -> usize {
+        self.eval.evaluations()
+    }
+
+    fn cache_hits(&self) -> usize {
+        self.eval.cache_hits()
+    }
+}
+
+impl<T, I, O> Fork for Tracking<T, I, O>
+where
+    T: Fork,
+    O: PartialOrd,
+{
+    fn fork(&self) -> Self {
+        Self::new(self.eval.fork(), self.direction)
+    }
+
+    fn join(&mut self, other: Self) {
+        self.eval.join(other.eval);
+        if let Some((solution, objective)) = other.best
+            && self.improves(&objective)
+        {
+            self.best = Some((solution, objective));
+        }
+    }
+}
+
+pub(crate) fn best_of<'a, I, P, E>(
+    solutions: impl Iterator<Item = &'a I>,
+    direction: Direction,
+    problem: &P,
+    eval: &mut E,
+) -> Option<(&'a I, E::Objective)>
+where
+    I: 'a,
+    E: Eval<P, I>,
+{
+    solutions
+        .map(|solution| (solution, eval.eval(solution, problem)))
+        .reduce(|best, candidate| {
+            if direction.rank(&candidate.1) > direction.rank(&best.1) {
+                candidate
+            } else {
+                best
+            }
+        })
+}
+
+impl<T, I, O> Reset for KeepBest<T, I, O>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+        self.best = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use core::convert::Infallible;
+
+    use crate::{
+        eval::{self, FromFn},
+        op::{
+            self,
+            AcceptIf,
+            Ignore,
+            Then,
+            accept::{ThresholdAccepting, cooling::Geometric},
+            init::{self, FromIndividual},
+            stop::Iterations,
+        },
+    };
+
+    use super::*;
+
+    // NOTE: This moves to the next solution in `path` every time it is applied.
+    struct Walk {
+        path: Vec<i32>,
+        step: usize,
+    }
+
+    impl<P, E> Operator<P, Individual<i32>, E> for Walk
+    where
+        E: Eval<P, i32>,
+    {
+        type Output = ();
+
+        type Error = Infallible;
+
+        fn apply(
+            &mut self,
+            solution: &mut Individual<i32>,
+            _problem: &P,
+            _eval: &mut E,
+            _input: (),
+        ) -> Result<Self::Output, Self::Error> {
+            **solution = self.path[self.step];
+            self.step += 1;
+            Ok(())
+        }
+    }
+
+    impl Reset for Walk {
+        fn reset(&mut self) {
+            self.step = 0;
+        }
+    }
+
+    type Identity = FromFn<fn(&i32, &()) -> i32>;
+
+    fn identity() -> Identity {
+        let identity: fn(&i32, &()) -> i32 = |solution, _problem| *solution;
+        eval::from_fn(identity)
+    }
+
+    type Walker = Then<
+        FromIndividual<i32>,
+        RepeatUntil<Ignore<AcceptIf<Walk, ThresholdAccepting<Geometric>>>, Iterations>,
+    >;
+
+    // NOTE: The threshold is high enough to accept every step, so the walk ends on 5 even though it went through 7 and 2.
+    fn walk() -> Walker {
+        let walk = Walk {
+            path: vec![3, 7, 2, 5],
+            step: 0,
+        };
+        let threshold = ThresholdAccepting::new(Geometric::new(100.0, 1.0));
+        let step = Operator::<(), Individual<i32>, Identity>::accept_if(walk, threshold);
+        let step = Operator::<(), Individual<i32>, Identity, _>::ignore(step);
+        let repeat =
+            Operator::<(), Individual<i32>, Identity>::repeat_until(step, Iterations::new(4));
+        Operator::<(), Individual<i32>, Identity>::then(init::from_individual(4), repeat)
+    }
+
+    #[test]
+    fn solver_returns_best_solution_instead_of_final_one() {
+        let mut solver = walk();
+        assert_eq!(*solver.optimize(&(), &mut identity()).unwrap(), 5);
+
+        let mut solver = op::keep_best(walk());
+        assert_eq!(*solver.optimize(&(), &mut identity()).unwrap(), 7);
+        assert_eq!(solver.best_objective(), Some(&7));
+        assert_eq!(
+            *solver.optimize(&(), &mut identity().minimize()).unwrap(),
+            2
+        );
+        assert_eq!(solver.best_objective(), Some(&2));
+    }
+
+    #[test]
+    fn loop_returns_best_solution_instead_of_final_one() {
+        let Then { first, second } = walk();
+        let mut solver = Operator::<(), Individual<i32>, Identity>::then(first, second.keep_best());
+        assert_eq!(*solver.optimize(&(), &mut identity()).unwrap(), 7);
+        assert_eq!(
+            *solver.optimize(&(), &mut identity().minimize()).unwrap(),
+            2
+        );
+    }
+
+    // NOTE: This looks at a solution better than any it returns.
+    struct Wander;
+
+    impl<P, E> Optimize<P, Vec<i32>, E> for Wander
+    where
+        E: Eval<P, i32>,
+    {
+        type Error = Infallible;
+
+        fn optimize(&mut self, problem: &P, eval: &mut E) -> Result<Vec<i32>, Self::Error> {
+            let _ = eval.eval(&9, problem);
+            Ok(vec![2, 1, 3])
+        }
+    }
+
+    #[test]
+    fn solver_replaces_worst_individual_with_best_solution() {
+        let mut solver = op::keep_best(Wander);
+        assert_eq!(solver.optimize(&(), &mut identity()).unwrap(), [2, 9, 3]);
+        assert_eq!(solver.best(), Some(&9));
+
+        // NOTE: The population already contains a better individual when minimising.
+        assert_eq!(
+            solver.optimize(&(), &mut identity().minimize()).unwrap(),
+            [2, 1, 3]
+        );
+    }
+}
@@ -0,0 +1,398 @@
+use core::error::Error;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{eval::Eval, solution::Solution};
+
+use super::{Operator, mutate::Mutate, search::Search};
+
+// NOTE: This is implemented for tuples (of operators with the same output and error types), arrays, slices, and `Vec`s of
+//       operators, which lets combinators such as `VariableNeighbourhoodDescent` and `Choose` apply the operators in a list
+//       by their index. Lists of operators with different types can be written as tuples or as a `Vec` of boxed operators
+//       (see `Operator::boxed`).
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait OperatorList<P, S, E, In = ()>
+where
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    type Output;
+
+    type Error: Error;
+
+    #[must_use]
+    fn len(&self) -> usize;
+
+    #[must_use]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // NOTE: This panics if `index` is out of bounds.
+    fn apply_nth(
+        &mut self,
+        index: usize,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+        input: In,
+    ) -> Result<Self::Output, Self::Error>;
+}
+
+// NOTE: Like `OperatorList`, but for lists of `Mutate` operators.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait MutateList<P, S, E>: OperatorList<P, S, E>
+where
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    // NOTE: This panics if `index` is out of bounds.
+    fn mutate_nth(
+        &mut self,
+        index: usize,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<(), Self::Error>;
+}
+
+// NOTE: Like `OperatorList`, but for lists of `Search` operators.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait SearchList<P, S, E>: OperatorList<P, S, E>
+where
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    // NOTE: This panics if `index` is out of bounds.
+    fn search_nth(
+        &mut self,
+        index: usize,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error>;
+}
+
+impl<T, P, S, E, In> OperatorList<P, S, E, In> for &mut T
+where
+    T: OperatorList<P, S, E, In> + ?Sized,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    type Output = T::Output;
+
+    type Error = T::Error;
+
+    fn len(&self) -> usize {
+        T::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        T::is_empty(self)
+    }
+
+    fn apply_nth(
+        &mut self,
+        index: usize,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+        input: In,
+    ) -> Result<Self::Output, Self::Error> {
+        T::apply_nth(self, index, solution, problem, eval, input)
+    }
+}
+
+impl<T, P, S, E> MutateList<P, S, E> for &mut T
+where
+    T: MutateList<P, S, E> + ?Sized,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn mutate_nth(
+        &mut self,
+        index: usize,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<(), Self::Error> {
+        T::mutate_nth(self, index, solution, problem, eval)
+    }
+}
+
+impl<T, P, S, E> SearchList<P, S, E> for &mut T
+where
+    T: SearchList<P, S, E> + ?Sized,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn search_nth(
+        &mut self,
+        index: usize,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error> {
+        T::search_nth(self, index, solution, problem, eval)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, P, S, E, In> OperatorList<P, S, E, In> for Box<T>
+where
+    T: OperatorList<P, S, E, In> + ?Sized,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    type Output = T::Output;
+
+    type Error = T::Error;
+
+    fn len(&self) -> usize {
+        T::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        T::is_empty(self)
+    }
+
+    fn apply_nth(
+        &mut self,
+        index: usize,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+        input: In,
+    ) -> Result<Self::Output, Self::Error> {
+        T::apply_nth(self, index, solution, problem, eval, input)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, P, S, E> MutateList<P, S, E> for Box<T>
+where
+    T: MutateList<P, S, E> + ?Sized,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn mutate_nth(
+        &mut self,
+        index: usize,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<(), Self::Error> {
+        T::mutate_nth(self, index, solution, problem, eval)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, P, S, E> SearchList<P, S, E> for Box<T>
+where
+    T: SearchList<P, S, E> + ?Sized,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn search_nth(
+        &mut self,
+        index: usize,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error> {
+        T::search_nth(self, index, solution, problem, eval)
+    }
+}
+
+// NOTE: Slices, arrays and `Vec`s all index into a slice of operators, so they share their impls.
+macro_rules! impl_for_slices {
+    ($(($($generics:tt)*) $ty:ty $(where $cfg:meta)?),+ $(,)?) => {
+        $(
+            $(#[cfg($cfg)])?
+            impl<T, P, S, E, In, $($generics)*> OperatorList<P, S, E, In> for $ty
+            where
+                T: Operator<P, S, E, In>,
+                S: Solution,
+                E: Eval<P, S::Individual>,
+            {
+                type Output = T::Output;
+
+                type Error = T::Error;
+
+                fn len(&self) -> usize {
+                    <[T]>::len(self)
+                }
+
+                fn apply_nth(
+                    &mut self,
+                    index: usize,
+                    solution: &mut S,
+                    problem: &P,
+                    eval: &mut E,
+                    input: In,
+                ) -> Result<Self::Output, Self::Error> {
+                    self[index].apply(solution, problem, eval, input)
+                }
+            }
+
+            $(#[cfg($cfg)])?
+            impl<T, P, S, E, $($generics)*> MutateList<P, S, E> for $ty
+            where
+                T: Mutate<P, S, E>,
+                S: Solution,
+                E: Eval<P, S::Individual>,
+            {
+                fn mutate_nth(
+                    &mut self,
+                    index: usize,
+                    solution: &mut S,
+                    problem: &P,
+                    eval: &mut E,
+                ) -> Result<(), Self::Error> {
+                    self[index].mutate(solution, problem, eval)
+                }
+            }
+
+            $(#[cfg($cfg)])?
+            impl<T, P, S, E, $($generics)*> SearchList<P, S, E> for $ty
+            where
+                T: Search<P, S, E>,
+                S: Solution,
+                E: Eval<P, S::Individual>,
+            {
+                fn search_nth(
+                    &mut self,
+                    index: usize,
+                    solution: &mut S,
+                    problem: &P,
+                    eval: &mut E,
+                ) -> Result<bool, Self::Error> {
+                    self[index].search(solution, problem, eval)
+                }
+            }
+        )+
+    };
+}
+
+impl_for_slices!(
+    () [T],
+    (const N: usize) [T; N],
+    () Vec<T> where feature = "alloc",
+);
+
+macro_rules! impl_for_tuples {
+    ($(($first:ident $(, $rest:ident)*)),+ $(,)?) => {
+        $(
+            impl<$first, $($rest,)* P, S, E, In> OperatorList<P, S, E, In> for ($first, $($rest,)*)
+            where
+                $first: Operator<P, S, E, In>,
+                $($rest: Operator<P, S, E, In, Output = $first::Output, Error = $first::Error>,)*
+                S: Solution,
+                E: Eval<P, S::Individual>,
+            {
+                type Output = $first::Output;
+
+                type Error = $first::Error;
+
+                fn len(&self) -> usize {
+                    [stringify!($first), $(stringify!($rest),)*].len()
+                }
+
+                #[allow(non_snake_case)]
+                fn apply_nth(
+                    &mut self,
+                    index: usize,
+                    solution: &mut S,
+                    problem: &P,
+                    eval: &mut E,
+                    input: In,
+                ) -> Result<Self::Output, Self::Error> {
+                    let len = self.len();
+                    let ($first, $($rest,)*) = self;
+                    let mut indices = 0..;
+                    if indices.next() == Some(index) {
+                        return $first.apply(solution, problem, eval, input);
+                    }
+                    $(
+                        if indices.next() == Some(index) {
+                            return $rest.apply(solution, problem, eval, input);
+                        }
+                    )*
+                    panic!("index out of bounds: the len is {len} but the index is {index}")
+                }
+            }
+
+            impl<$first, $($rest,)* P, S, E> MutateList<P, S, E> for ($first, $($rest,)*)
+            where
+                $first: Mutate<P, S, E>,
+                $($rest: Mutate<P, S, E, Output = $first::Output, Error = $first::Error>,)*
+                S: Solution,
+                E: Eval<P, S::Individual>,
+            {
+                #[allow(non_snake_case)]
+                fn mutate_nth(
+                    &mut self,
+                    index: usize,
+                    solution: &mut S,
+                    problem: &P,
+                    eval: &mut E,
+                ) -> Result<(), Self::Error> {
+                    let len = self.len();
+                    let ($first, $($rest,)*) = self;
+                    let mut indices = 0..;
+                    if indices.next() == Some(index) {
+                        return $first.mutate(solution, problem, eval);
+                    }
+                    $(
+                        if indices.next() == Some(index) {
+                            return $rest.mutate(solution, problem, eval);
+                        }
+                    )*
+                    panic!("index out of bounds: the len is {len} but the index is {index}")
+                }
+            }
+
+            impl<$first, $($rest,)* P, S, E> SearchList<P, S, E> for ($first, $($rest,)*)
+            where
+                $first: Search<P, S, E>,
+                $($rest: Search<P, S, E, Output = $first::Output, Error = $first::Error>,)*
+                S: Solution,
+                E: Eval<P, S::Individual>,
+            {
+                #[allow(non_snake_case)]
+                fn search_nth(
+                    &mut self,
+                    index: usize,
+                    solution: &mut S,
+                    problem: &P,
+                    eval: &mut E,
+                ) -> Result<bool, Self::Error> {
+                    let len = self.len();
+                    let ($first, $($rest,)*) = self;
+                    let mut indices = 0..;
+                    if indices.next() == Some(index) {
+                        return $first.search(solution, problem, eval);
+                    }
+                    $(
+                        if indices.next() == Some(index) {
+                            return $rest.search(solution, problem, eval);
+                        }
+                    )*
+                    panic!("index out of bounds: the len is {len} but the index is {index}")
+                }
+            }
+        )+
+    };
+}
+
+impl_for_tuples!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, F),
+    (A, B, C, D, F, G),
+    (A, B, C, D, F, G, H),
+    (A, B, C, D, F, G, H, I),
+);
@@ -0,0 +1,585 @@
+use crate::{eval::Eval, observe::Event, solution::Solution};
+
+use super::{Reset, accept::Accept, stop::Stop};
+
+// NOTE: `And` short-circuits just like `&&` - the second condition is only checked if the first one is met. This matters
+//       for stateful conditions (such as `Iterations`), which are only updated when they are checked, so put those first
+//       if they should always be updated.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct And<T, U> {
+    pub(super) first: T,
+    pub(super) second: U,
+}
+
+impl<T, U, P, S, E> Stop<P, S, E> for And<T, U>
+where
+    T: Stop<P, S, E>,
+    U: Stop<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn stop(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.first.stop(solution, problem, eval) && self.second.stop(solution, problem, eval)
+    }
+}
+
+impl<T, U, P, S, E> Accept<P, S, E> for And<T, U>
+where
+    T: Accept<P, S, E>,
+    U: Accept<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn accept(&mut self, solution: &S, prev_solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.first.accept(solution, prev_solution, problem, eval)
+            && self.second.accept(solution, prev_solution, problem, eval)
+    }
+}
+
+// NOTE: `Or` short-circuits just like `||` - the second condition is only checked if the first one is not met (see the
+//       note above on `And`). When it stops a loop, it reports which of the two conditions was met (see
+//       `Stop::stop_with_cause`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Or<T, U> {
+    pub(super) first: T,
+    pub(super) second: U,
+}
+
+impl<T, U, P, S, E> Stop<P, S, E> for Or<T, U>
+where
+    T: Stop<P, S, E>,
+    U: Stop<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn stop(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.first.stop(solution, problem, eval) || self.second.stop(solution, problem, eval)
+    }
+
+    fn stop_with_cause(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        let index = if self.first.stop_with_cause(solution, problem, eval) {
+            0
+        } else if self.second.stop_with_cause(solution, problem, eval) {
+            1
+        } else {
+            return false;
+        };
+        eval.observe(Event::StoppedBy { index });
+        true
+    }
+}
+
+impl<T, U, P, S, E> Accept<P, S, E> for Or<T, U>
+where
+    T: Accept<P, S, E>,
+    U: Accept<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn accept(&mut self, solution: &S, prev_solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.first.accept(solution, prev_solution, problem, eval)
+            || self.second.accept(solution, prev_solution, problem, eval)
+    }
+}
+
+// NOTE: Unlike `And` and `Or`, this cannot short-circuit, so both conditions are always checked (first to second).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Xor<T, U> {
+    pub(super) first: T,
+    pub(super) second: U,
+}
+
+impl<T, U, P, S, E> Stop<P, S, E> for Xor<T, U>
+where
+    T: Stop<P, S, E>,
+    U: Stop<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn stop(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.first.stop(solution, problem, eval) ^ self.second.stop(solution, problem, eval)
+    }
+
+    // NOTE: Only the condition that was met is reported, but not what made it stop, since that is only known once the other
+    //       condition has been checked as well.
+    fn stop_with_cause(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        let first = self.first.stop(solution, problem, eval);
+        let second = self.second.stop(solution, problem, eval);
+        if first == second {
+            return false;
+        }
+        eval.observe(Event::StoppedBy {
+            index: usize::from(second),
+        });
+        true
+    }
+}
+
+impl<T, U, P, S, E> Accept<P, S, E> for Xor<T, U>
+where
+    T: Accept<P, S, E>,
+    U: Accept<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn accept(&mut self, solution: &S, prev_solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.first.accept(solution, prev_solution, problem, eval)
+            ^ self.second.accept(solution, prev_solution, problem, eval)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Not<T>(pub(super) T);
+
+impl<T> AsRef<T> for Not<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsMut<T> for Not<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, P, S, E> Stop<P, S, E> for Not<T>
+where
+    T: Stop<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn stop(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        !self.0.stop(solution, problem, eval)
+    }
+}
+
+impl<T, P, S, E> Accept<P, S, E> for Not<T>
+where
+    T: Accept<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn accept(&mut self, solution: &S, prev_solution: &S, problem: &P, eval: &mut E) -> bool {
+        !self.0.accept(solution, prev_solution, problem, eval)
+    }
+}
+
+// NOTE: Like `And`, this short-circuits just like `Iterator::all` - the conditions are checked in order, and only until
+//       one of them is not met.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct All<T>(pub(super) T);
+
+impl<T> AsRef<T> for All<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsMut<T> for All<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+// NOTE: Like `Or`, this short-circuits just like `Iterator::any` - the conditions are checked in order, and only until one
+//       of them is met. When it stops a loop, it reports the index of that condition (see `Stop::stop_with_cause`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Any<T>(pub(super) T);
+
+impl<T> AsRef<T> for Any<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsMut<T> for Any<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, P, S, E, const N: usize> Stop<P, S, E> for All<[T; N]>
+where
+    T: Stop<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn stop(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.0
+            .iter_mut()
+            .all(|cond| cond.stop(solution, problem, eval))
+    }
+}
+
+impl<T, P, S, E, const N: usize> Stop<P, S, E> for Any<[T; N]>
+where
+    T: Stop<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn stop(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.0
+            .iter_mut()
+            .any(|cond| cond.stop(solution, problem, eval))
+    }
+
+    fn stop_with_cause(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        let Some(index) = self
+            .0
+            .iter_mut()
+            .position(|cond| cond.stop_with_cause(solution, problem, eval))
+        else {
+            return false;
+        };
+        eval.observe(Event::StoppedBy { index });
+        true
+    }
+}
+
+impl<T, P, S, E, const N: usize> Accept<P, S, E> for All<[T; N]>
+where
+    T: Accept<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn accept(&mut self, solution: &S, prev_solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.0
+            .iter_mut()
+            .all(|cond| cond.accept(solution, prev_solution, problem, eval))
+    }
+}
+
+impl<T, P, S, E, const N: usize> Accept<P, S, E> for Any<[T; N]>
+where
+    T: Accept<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn accept(&mut self, solution: &S, prev_solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.0
+            .iter_mut()
+            .any(|cond| cond.accept(solution, prev_solution, problem, eval))
+    }
+}
+
+macro_rules! impl_for_tuples {
+    ($(($($name:ident),+)),+ $(,)?) => {
+        $(
+            impl<$($name,)+ P, S, E> Stop<P, S, E> for All<($($name,)+)>
+            where
+                $($name: Stop<P, S, E>,)+
+                S: Solution,
+                E: Eval<P, S::Individual>,
+            {
+                #[allow(non_snake_case)]
+                fn stop(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+                    let ($($name,)+) = &mut self.0;
+                    $(
+                        if !$name.stop(solution, problem, eval) {
+                            return false;
+                        }
+                    )+
+                    true
+                }
+            }
+
+            impl<$($name,)+ P, S, E> Stop<P, S, E> for Any<($($name,)+)>
+            where
+                $($name: Stop<P, S, E>,)+
+                S: Solution,
+                E: Eval<P, S::Individual>,
+            {
+                #[allow(non_snake_case)]
+                fn stop(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+                    let ($($name,)+) = &mut self.0;
+                    $(
+                        if $name.stop(solution, problem, eval) {
+                            return true;
+                        }
+                    )+
+                    false
+                }
+
+                #[allow(non_snake_case, unused_assignments)]
+                fn stop_with_cause(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+                    let ($($name,)+) = &mut self.0;
+                    let mut index = 0;
+                    $(
+                        if $name.stop_with_cause(solution, problem, eval) {
+                            eval.observe(Event::StoppedBy { index });
+                            return true;
+                        }
+                        index += 1;
+                    )+
+                    false
+                }
+            }
+
+            impl<$($name,)+ P, S, E> Accept<P, S, E> for All<($($name,)+)>
+            where
+                $($name: Accept<P, S, E>,)+
+                S: Solution,
+                E: Eval<P, S::Individual>,
+            {
+                #[allow(non_snake_case)]
+                fn accept(&mut self, solution: &S, prev_solution: &S, problem: &P, eval: &mut E) -> bool {
+                    let ($($name,)+) = &mut self.0;
+                    $(
+                        if !$name.accept(solution, prev_solution, problem, eval) {
+                            return false;
+                        }
+                    )+
+                    true
+                }
+            }
+
+            impl<$($name,)+ P, S, E> Accept<P, S, E> for Any<($($name,)+)>
+            where
+                $($name: Accept<P, S, E>,)+
+                S: Solution,
+                E: Eval<P, S::Individual>,
+            {
+                #[allow(non_snake_case)]
+                fn accept(&mut self, solution: &S, prev_solution: &S, problem: &P, eval: &mut E) -> bool {
+                    let ($($name,)+) = &mut self.0;
+                    $(
+                        if $name.accept(solution, prev_solution, problem, eval) {
+                            return true;
+                        }
+                    )+
+                    false
+                }
+            }
+        )+
+    };
+}
+
+impl_for_tuples!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, F),
+    (A, B, C, D, F, G),
+    (A, B, C, D, F, G, H),
+    (A, B, C, D, F, G, H, I),
+);
+
+// NOTE: These functions (which are re-exported from `op::stop` and `op::accept`) are an alternative to the combinator
+//       methods on `Stop` and `Accept`. Since they don't require their arguments to impl either trait, they don't suffer
+//       from the ambiguity errors caused by conditions with multiple `Stop` or `Accept` impls (such as `Optimum`).
+pub fn and<T, U>(first: T, second: U) -> And<T, U> {
+    And { first, second }
+}
+
+pub fn or<T, U>(first: T, second: U) -> Or<T, U> {
+    Or { first, second }
+}
+
+pub fn xor<T, U>(first: T, second: U) -> Xor<T, U> {
+    Xor { first, second }
+}
+
+pub fn not<T>(cond: T) -> Not<T> {
+    Not(cond)
+}
+
+pub fn all<T>(conds: T) -> All<T> {
+    All(conds)
+}
+
+pub fn any<T>(conds: T) -> Any<T> {
+    Any(conds)
+}
+
+impl<T, U> Reset for And<T, U>
+where
+    T: Reset,
+    U: Reset,
+{
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
+
+impl<T, U> Reset for Or<T, U>
+where
+    T: Reset,
+    U: Reset,
+{
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
+
+impl<T, U> Reset for Xor<T, U>
+where
+    T: Reset,
+    U: Reset,
+{
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
+
+impl<T> Reset for Not<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl<T> Reset for All<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl<T> Reset for Any<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        eval::{self, FromFn},
+        op::accept::Accept,
+        solution::Individual,
+    };
+
+    use super::*;
+
+    // NOTE: A condition that always gives the same answer, and counts how often it was asked.
+    #[derive(Default)]
+    struct Count {
+        result: bool,
+        calls: usize,
+    }
+
+    fn count(result: bool) -> Count {
+        Count { result, calls: 0 }
+    }
+
+    type Identity = FromFn<fn(&i32, &()) -> i32>;
+
+    impl Stop<(), Individual<i32>, Identity> for Count {
+        fn stop(
+            &mut self,
+            _solution: &Individual<i32>,
+            _problem: &(),
+            _eval: &mut Identity,
+        ) -> bool {
+            self.calls += 1;
+            self.result
+        }
+    }
+
+    impl Accept<(), Individual<i32>, Identity> for Count {
+        fn accept(
+            &mut self,
+            _solution: &Individual<i32>,
+            _prev_solution: &Individual<i32>,
+            _problem: &(),
+            _eval: &mut Identity,
+        ) -> bool {
+            self.calls += 1;
+            self.result
+        }
+    }
+
+    fn stop<F>(cond: &mut F) -> bool
+    where
+        F: Stop<(), Individual<i32>, Identity>,
+    {
+        let mut eval: Identity = eval::from_fn(|solution, _problem| *solution);
+        cond.stop(&Individual::new(0), &(), &mut eval)
+    }
+
+    fn accept<F>(cond: &mut F) -> bool
+    where
+        F: Accept<(), Individual<i32>, Identity>,
+    {
+        let mut eval: Identity = eval::from_fn(|solution, _problem| *solution);
+        let solution = Individual::new(0);
+        cond.accept(&solution, &solution, &(), &mut eval)
+    }
+
+    #[test]
+    fn and_or_only_check_second_condition_if_needed() {
+        let mut cond = and(count(false), count(true));
+        assert!(!stop(&mut cond));
+        assert_eq!((cond.first.calls, cond.second.calls), (1, 0));
+
+        let mut cond = and(count(true), count(false));
+        assert!(!accept(&mut cond));
+        assert_eq!((cond.first.calls, cond.second.calls), (1, 1));
+
+        let mut cond = or(count(true), count(false));
+        assert!(stop(&mut cond));
+        assert_eq!((cond.first.calls, cond.second.calls), (1, 0));
+
+        let mut cond = or(count(false), count(true));
+        assert!(accept(&mut cond));
+        assert_eq!((cond.first.calls, cond.second.calls), (1, 1));
+    }
+
+    #[test]
+    fn xor_and_not_check_every_condition() {
+        let mut cond = xor(count(true), count(true));
+        assert!(!stop(&mut cond));
+        assert_eq!((cond.first.calls, cond.second.calls), (1, 1));
+
+        let mut cond = xor(count(false), count(true));
+        assert!(accept(&mut cond));
+        assert_eq!((cond.first.calls, cond.second.calls), (1, 1));
+
+        let mut cond = not(count(false));
+        assert!(stop(&mut cond));
+        assert_eq!(cond.0.calls, 1);
+    }
+
+    #[test]
+    fn all_any_stop_at_first_deciding_condition() {
+        let mut cond = all([count(true), count(false), count(true)]);
+        assert!(!stop(&mut cond));
+        assert_eq!(cond.0.each_ref().map(|cond| cond.calls), [1, 1, 0]);
+
+        let mut cond = any((count(false), count(true), count(false)));
+        assert!(accept(&mut cond));
+        assert_eq!((cond.0.0.calls, cond.0.1.calls, cond.0.2.calls), (1, 1, 0));
+
+        let mut cond = all((count(true), count(true)));
+        assert!(accept(&mut cond));
+        assert_eq!((cond.0.0.calls, cond.0.1.calls), (1, 1));
+
+        let mut cond = any([count(false), count(false)]);
+        assert!(!stop(&mut cond));
+        assert_eq!(cond.0.each_ref().map(|cond| cond.calls), [1, 1]);
+    }
+}
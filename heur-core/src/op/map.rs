@@ -5,7 +5,7 @@ use core::{
 
 use crate::{eval::Eval, solution::Solution};
 
-use super::{Operator, init::Init, mutate::Mutate, search::Search};
+use super::{Operator, Reset, init::Init, mutate::Mutate, search::Search};
 
 // TODO: Should this impl `Init`, `Mutate`, and/or `Search`?
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
@@ -128,7 +128,7 @@ where
     E: Eval<P, S::Individual>,
     Err: Error,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
         self.op.search(solution, problem, eval).map_err(&mut self.f)
     }
 }
@@ -188,3 +188,30 @@ where
             .finish_non_exhaustive()
     }
 }
+
+impl<T, F> Reset for Map<T, F>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+    }
+}
+
+impl<T, F> Reset for MapErr<T, F>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+    }
+}
+
+impl<T, F> Reset for TryMap<T, F>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+    }
+}
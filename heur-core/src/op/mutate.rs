@@ -1,9 +1,14 @@
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
-use crate::{eval::Eval, solution::Solution};
+use core::convert::Infallible;
 
-use super::Operator;
+use crate::{
+    eval::{Delta, Eval},
+    solution::{Individual, Move, Solution},
+};
+
+use super::{Operator, Reset};
 
 // TODO: Add `#[diagnostic::on_unimplemented]`
 pub trait Mutate<P, S, E>: Operator<P, S, E>
@@ -14,6 +19,93 @@ where
     fn mutate(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error>;
 }
 
+// NOTE: Mutations that change a solution by applying moves can impl this (in addition to `Mutate`), which lets them apply
+//       their moves through the evaluator when wrapped in `Incremental`. `apply` is called with every move in turn, and
+//       must be used to apply it.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait MoveMutation<P, S> {
+    type Move: Move<S>;
+
+    fn mutate_with<F>(&mut self, solution: &mut S, problem: &P, apply: F)
+    where
+        F: FnMut(&mut S, &Self::Move);
+}
+
+// NOTE: This is the counterpart of `op::search::Incremental` for mutations. It applies the moves of the wrapped mutation
+//       through the evaluator's `Delta` impl, which lets caching evaluators (such as `Cached`) update the objective value
+//       of the solution incrementally, rather than having to evaluate it again from scratch (see `Delta::apply_move`).
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Incremental<T>(pub(super) T);
+
+impl<T> Incremental<T> {
+    // NOTE: This lets mutations defined in other crates provide an `incremental` method.
+    pub fn new(op: T) -> Self {
+        Self(op)
+    }
+}
+
+impl<T> AsRef<T> for Incremental<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsMut<T> for Incremental<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, P, S, E> Operator<P, Individual<S>, E> for Incremental<T>
+where
+    T: MoveMutation<P, S>,
+    E: Delta<P, S, T::Move>,
+{
+    type Output = ();
+
+    type Error = Infallible;
+
+    fn apply(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.mutate(solution, problem, eval)
+    }
+}
+
+impl<T, P, S, E> Mutate<P, Individual<S>, E> for Incremental<T>
+where
+    T: MoveMutation<P, S>,
+    E: Delta<P, S, T::Move>,
+{
+    fn mutate(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<(), Self::Error> {
+        self.0
+            .mutate_with(&mut **solution, problem, |solution, mv| {
+                eval.apply_move(solution, problem, mv);
+            });
+        Ok(())
+    }
+}
+
+impl<T> Reset for Incremental<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
 impl<P, S, E> Mutate<P, S, E> for ()
 where
     S: Solution,
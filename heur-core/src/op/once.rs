@@ -1,10 +1,14 @@
 use crate::{eval::Eval, solution::Solution};
 
-use super::{Operator, mutate::Mutate, search::Search};
+use super::{Operator, Reset, mutate::Mutate, search::Search};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
-pub struct Once<T>(pub(super) Option<T>);
+pub struct Once<T> {
+    pub(super) op: T,
+    pub(super) done: bool,
+}
 
 impl<T, P, S, E, In> Operator<P, S, E, In> for Once<T>
 where
@@ -23,7 +27,11 @@ where
         eval: &mut E,
         input: In,
     ) -> Result<Self::Output, Self::Error> {
-        self.0.take().apply(solution, problem, eval, input)
+        if self.done {
+            return Ok(None);
+        }
+        self.done = true;
+        self.op.apply(solution, problem, eval, input).map(Some)
     }
 }
 
@@ -34,7 +42,11 @@ where
     E: Eval<P, S::Individual>,
 {
     fn mutate(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
-        self.0.take().mutate(solution, problem, eval)
+        if self.done {
+            return Ok(());
+        }
+        self.done = true;
+        self.op.mutate(solution, problem, eval)
     }
 }
 
@@ -44,7 +56,21 @@ where
     S: Solution,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
-        self.0.take().search(solution, problem, eval)
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
+        if self.done {
+            return Ok(false);
+        }
+        self.done = true;
+        self.op.search(solution, problem, eval)
+    }
+}
+
+impl<T> Reset for Once<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+        self.done = false;
     }
 }
@@ -1,9 +1,10 @@
 use crate::{eval::Eval, solution::Solution};
 
-use super::Operator;
+use super::{Operator, Reset};
 
 // TODO: Should this impl `Init`, `Mutate`, and/or `Search`?
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct Pipe<T, U> {
     pub(super) from: T,
@@ -33,3 +34,14 @@ where
         Ok(output)
     }
 }
+
+impl<T, U> Reset for Pipe<T, U>
+where
+    T: Reset,
+    U: Reset,
+{
+    fn reset(&mut self) {
+        self.from.reset();
+        self.to.reset();
+    }
+}
@@ -1,5 +1,8 @@
 use core::marker::PhantomData;
 
+#[cfg(feature = "rayon")]
+use alloc::vec::Vec;
+
 use crate::{
     eval::Eval,
     solution::{Individual, IterMut, Population},
@@ -10,6 +13,11 @@ use super::Operator;
 mod for_each;
 pub use for_each::ForEach;
 
+#[cfg(feature = "rayon")]
+mod par_for_each;
+#[cfg(feature = "rayon")]
+pub use par_for_each::ParForEach;
+
 pub fn for_each<P, S, E, In, T>(op: T) -> ForEach<T, P, S, E, In>
 where
     T: Operator<P, Individual<S::Individual>, E, In, Output = In>,
@@ -21,3 +29,18 @@ where
         marker: PhantomData,
     }
 }
+
+#[cfg(feature = "rayon")]
+pub fn par_for_each<P, S, E, T, F>(f: F) -> ParForEach<F, T, P, S, E>
+where
+    F: Fn() -> T,
+    T: Operator<P, Individual<S::Individual>, E, Output = ()> + Send,
+    S: Population + AsMut<[S::Individual]>,
+    E: Eval<P, S::Individual>,
+{
+    ParForEach {
+        f,
+        ops: Vec::new(),
+        marker: PhantomData,
+    }
+}
@@ -6,7 +6,7 @@ use core::{
 
 use crate::{
     eval::Eval,
-    op::{Operator, mutate::Mutate, search::Search},
+    op::{Operator, Reset, mutate::Mutate, search::Search},
     solution::{Individual, IterMut, Population},
 };
 
@@ -113,10 +113,25 @@ where
     S: Population + for<'a> IterMut<'a, Item = S::Individual>,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, population: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
+    fn search(
+        &mut self,
+        population: &mut S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error> {
+        let mut improved = false;
         for solution in population.iter_mut().map(Individual::from_mut) {
-            self.op.search(solution, problem, eval)?;
+            improved |= self.op.search(solution, problem, eval)?;
         }
-        Ok(())
+        Ok(improved)
+    }
+}
+
+impl<T, P, S, E, In> Reset for ForEach<T, P, S, E, In>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
     }
 }
@@ -0,0 +1,251 @@
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+
+use alloc::vec::Vec;
+
+use rayon::{current_num_threads, prelude::*};
+
+use crate::{
+    eval::{Eval, Fork},
+    op::{Operator, Reset, mutate::Mutate, search::Search},
+    solution::{Individual, Population},
+};
+
+// NOTE: Unlike `ForEach`, this stores a function that creates the operators rather than an operator. The population is
+//       split into one chunk per thread, and each chunk is processed by its own operator. Operators are created the first
+//       time they are needed and kept between applications, so operators that hold state (such as RNGs) continue from
+//       where they left off instead of starting over. Chunk `i` is always processed by operator `i`, so the results only
+//       depend on the number of threads. Resetting this drops the operators, so they are created again by the function.
+#[must_use]
+pub struct ParForEach<F, T, P, S, E> {
+    pub(super) f: F,
+    pub(super) ops: Vec<T>,
+    #[allow(clippy::type_complexity)]
+    pub(super) marker: PhantomData<fn() -> (P, S, E)>,
+}
+
+impl<F, T, P, S, E> ParForEach<F, T, P, S, E> {
+    // NOTE: Each chunk of the population is processed by a separate operator with a forked evaluator, which is joined back
+    //       into `eval` even if the operator fails. If several operators fail, the error from the earliest chunk is
+    //       returned. Otherwise, this returns whether any of the operators returned `true` (see `Search::search`).
+    fn for_each<Err, G>(
+        &mut self,
+        population: &mut S,
+        problem: &P,
+        eval: &mut E,
+        g: G,
+    ) -> Result<bool, Err>
+    where
+        F: Fn() -> T,
+        T: Send,
+        S: Population<Individual: Send> + AsMut<[S::Individual]>,
+        P: Sync,
+        E: Eval<P, S::Individual> + Fork + Send + Sync,
+        Err: Send,
+        G: Fn(&mut T, &mut Individual<S::Individual>, &P, &mut E) -> Result<bool, Err> + Sync,
+    {
+        let population = population.as_mut();
+        let chunk_size = population.len().div_ceil(current_num_threads()).max(1);
+        let num_chunks = population.len().div_ceil(chunk_size);
+        if self.ops.len() < num_chunks {
+            self.ops.resize_with(num_chunks, &self.f);
+        }
+
+        let forked = &*eval;
+        let chunks = population
+            .par_chunks_mut(chunk_size)
+            .zip(&mut self.ops)
+            .map(|(chunk, op)| {
+                let mut eval = forked.fork();
+                let result = chunk
+                    .iter_mut()
+                    .map(Individual::from_mut)
+                    .try_fold(false, |improved, solution| {
+                        Ok(g(op, solution, problem, &mut eval)? || improved)
+                    });
+                (result, eval)
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = Ok(false);
+        for (chunk_result, forked) in chunks {
+            eval.join(forked);
+            result = result.and_then(|improved| Ok(chunk_result? || improved));
+        }
+        result
+    }
+}
+
+impl<F, T, P, S, E> Debug for ParForEach<F, T, P, S, E>
+where
+    T: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ParForEach")
+            .field("ops", &self.ops)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F, T, P, S, E> Clone for ParForEach<F, T, P, S, E>
+where
+    F: Clone,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            f: self.f.clone(),
+            ops: self.ops.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<F, T, P, S, E> Operator<P, S, E> for ParForEach<F, T, P, S, E>
+where
+    F: Fn() -> T,
+    T: Operator<P, Individual<S::Individual>, E, Output = (), Error: Send> + Send,
+    S: Population<Individual: Send> + AsMut<[S::Individual]>,
+    P: Sync,
+    E: Eval<P, S::Individual> + Fork + Send + Sync,
+{
+    type Output = ();
+
+    type Error = T::Error;
+
+    fn apply(
+        &mut self,
+        population: &mut S,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.for_each(population, problem, eval, |op, solution, problem, eval| {
+            op.apply(solution, problem, eval, ()).map(|()| false)
+        })
+        .map(|_| ())
+    }
+}
+
+impl<F, T, P, S, E> Mutate<P, S, E> for ParForEach<F, T, P, S, E>
+where
+    F: Fn() -> T,
+    T: Mutate<P, Individual<S::Individual>, E, Output = (), Error: Send> + Send,
+    S: Population<Individual: Send> + AsMut<[S::Individual]>,
+    P: Sync,
+    E: Eval<P, S::Individual> + Fork + Send + Sync,
+{
+    fn mutate(&mut self, population: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
+        self.for_each(population, problem, eval, |op, solution, problem, eval| {
+            op.mutate(solution, problem, eval).map(|()| false)
+        })
+        .map(|_| ())
+    }
+}
+
+impl<F, T, P, S, E> Search<P, S, E> for ParForEach<F, T, P, S, E>
+where
+    F: Fn() -> T,
+    T: Search<P, Individual<S::Individual>, E, Output = (), Error: Send> + Send,
+    S: Population<Individual: Send> + AsMut<[S::Individual]>,
+    P: Sync,
+    E: Eval<P, S::Individual> + Fork + Send + Sync,
+{
+    fn search(
+        &mut self,
+        population: &mut S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error> {
+        self.for_each(population, problem, eval, |op, solution, problem, eval| {
+            op.search(solution, problem, eval)
+        })
+    }
+}
+
+impl<F, T, P, S, E> Reset for ParForEach<F, T, P, S, E> {
+    fn reset(&mut self) {
+        self.ops.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use core::convert::Infallible;
+
+    use rand::{Rng, SeedableRng, rngs::StdRng};
+    use rayon::ThreadPoolBuilder;
+
+    use crate::{
+        eval::{self, Counter},
+        op::population,
+    };
+
+    use super::*;
+
+    // NOTE: Every operator created by `jitter` starts from the same seed.
+    #[derive(Debug, Clone)]
+    struct Jitter(StdRng);
+
+    fn jitter() -> Jitter {
+        Jitter(StdRng::seed_from_u64(0))
+    }
+
+    impl<P, E> Operator<P, Individual<i32>, E> for Jitter
+    where
+        E: Eval<P, i32>,
+    {
+        type Output = ();
+
+        type Error = Infallible;
+
+        fn apply(
+            &mut self,
+            solution: &mut Individual<i32>,
+            problem: &P,
+            eval: &mut E,
+            _input: (),
+        ) -> Result<Self::Output, Self::Error> {
+            **solution += self.0.random_range(0..100);
+            let _ = eval.eval(solution, problem);
+            Ok(())
+        }
+    }
+
+    // NOTE: This does sequentially what `ParForEach` does using the given number of threads.
+    fn sequential(population: &mut [i32], threads: usize) {
+        let mut eval = eval::from_fn(|solution: &i32, _: &()| *solution);
+        let chunk_size = population.len().div_ceil(threads).max(1);
+        for chunk in population.chunks_mut(chunk_size) {
+            let mut op = jitter();
+            for solution in chunk {
+                op.apply(Individual::from_mut(solution), &(), &mut eval, ())
+                    .unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn matches_sequential_run_for_each_number_of_threads() {
+        for threads in [1, 3, 4] {
+            let pool = ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let mut population = vec![0; 10];
+            let mut eval = eval::from_fn(|solution: &i32, _: &()| *solution).counted();
+            let mut op = population::par_for_each(jitter);
+            pool.install(|| op.apply(&mut population, &(), &mut eval, ()))
+                .unwrap();
+
+            let mut expected = vec![0; 10];
+            sequential(&mut expected, threads);
+            assert_eq!(population, expected);
+            assert_eq!(eval.evaluations(), 10);
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use crate::{eval::Eval, solution::Solution};
+use crate::{eval::Eval, observe::Event, solution::Solution};
 
-use super::{Operator, mutate::Mutate, search::Search, stop::Stop};
+use super::{KeepBest, Operator, Reset, mutate::Mutate, search::Search, stop::Stop};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct Repeat<T> {
     pub(super) op: T,
@@ -53,21 +54,42 @@ where
     S: Solution,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
+        let mut improved = false;
         for _ in 0..self.times {
-            self.op.search(solution, problem, eval)?;
+            improved |= self.op.search(solution, problem, eval)?;
         }
-        Ok(())
+        Ok(improved)
     }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct RepeatUntil<T, F> {
     pub(super) op: T,
     pub(super) cond: F,
 }
 
+impl<T, F> RepeatUntil<T, F> {
+    pub fn cond(&self) -> &F {
+        &self.cond
+    }
+
+    pub fn cond_mut(&mut self) -> &mut F {
+        &mut self.cond
+    }
+
+    // NOTE: Unlike the best solution itself (see `Stop::track`), putting it back at the end relies on knowing when the
+    //       loop ends, which is why this is not a method on `Operator` or `Stop`.
+    pub fn keep_best<I, O>(self) -> KeepBest<Self, I, O> {
+        KeepBest {
+            op: self,
+            best: None,
+        }
+    }
+}
+
 impl<T, F, P, S, E, In> Operator<P, S, E, In> for RepeatUntil<T, F>
 where
     T: Operator<P, S, E, In, Output = In>,
@@ -86,9 +108,16 @@ where
         eval: &mut E,
         mut input: In,
     ) -> Result<Self::Output, Self::Error> {
-        while !self.cond.stop(solution, problem, eval) {
+        let mut iteration = 0;
+        while !self.cond.stop_with_cause(solution, problem, eval) {
+            eval.observe(Event::IterationStarted { iteration });
             input = self.op.apply(solution, problem, eval, input)?;
+            eval.observe(Event::IterationFinished { iteration });
+            iteration += 1;
         }
+        eval.observe(Event::Stopped {
+            iterations: iteration,
+        });
         Ok(input)
     }
 }
@@ -101,9 +130,16 @@ where
     E: Eval<P, S::Individual>,
 {
     fn mutate(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
-        while !self.cond.stop(solution, problem, eval) {
+        let mut iteration = 0;
+        while !self.cond.stop_with_cause(solution, problem, eval) {
+            eval.observe(Event::IterationStarted { iteration });
             self.op.mutate(solution, problem, eval)?;
+            eval.observe(Event::IterationFinished { iteration });
+            iteration += 1;
         }
+        eval.observe(Event::Stopped {
+            iterations: iteration,
+        });
         Ok(())
     }
 }
@@ -115,10 +151,218 @@ where
     S: Solution,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
-        while !self.cond.stop(solution, problem, eval) {
-            self.op.search(solution, problem, eval)?;
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
+        let mut iteration = 0;
+        let mut improved = false;
+        while !self.cond.stop_with_cause(solution, problem, eval) {
+            eval.observe(Event::IterationStarted { iteration });
+            improved |= self.op.search(solution, problem, eval)?;
+            eval.observe(Event::IterationFinished { iteration });
+            iteration += 1;
         }
-        Ok(())
+        eval.observe(Event::Stopped {
+            iterations: iteration,
+        });
+        Ok(improved)
+    }
+}
+
+// NOTE: This repeatedly applies a search operator until it no longer improves the solution (see `Search::search`), i.e.
+//       until a local optimum has been reached, or until the optional maximum number of steps has been taken.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct UntilLocalOptimum<T> {
+    pub(super) op: T,
+    pub(super) max_steps: Option<usize>,
+}
+
+impl<T> UntilLocalOptimum<T> {
+    pub fn max_steps(self, max_steps: usize) -> Self {
+        Self {
+            max_steps: Some(max_steps),
+            ..self
+        }
+    }
+
+    fn run<P, S, E>(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, T::Error>
+    where
+        T: Search<P, S, E>,
+        S: Solution,
+        E: Eval<P, S::Individual>,
+    {
+        let mut step = 0;
+        while self.max_steps.is_none_or(|max_steps| step < max_steps) {
+            let improved = self.op.search(solution, problem, eval)?;
+            eval.observe(Event::LocalSearchStep { step, improved });
+            if !improved {
+                break;
+            }
+            step += 1;
+        }
+        eval.observe(Event::LocalSearchFinished { steps: step });
+        Ok(step > 0)
+    }
+}
+
+impl<T, P, S, E> Operator<P, S, E> for UntilLocalOptimum<T>
+where
+    T: Search<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    type Output = ();
+
+    type Error = T::Error;
+
+    fn apply(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.run(solution, problem, eval).map(|_| ())
+    }
+}
+
+impl<T, P, S, E> Search<P, S, E> for UntilLocalOptimum<T>
+where
+    T: Search<P, S, E>,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
+        self.run(solution, problem, eval)
+    }
+}
+
+impl<T> Reset for Repeat<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+    }
+}
+
+impl<T, F> Reset for RepeatUntil<T, F>
+where
+    T: Reset,
+    F: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+        self.cond.reset();
+    }
+}
+
+impl<T> Reset for UntilLocalOptimum<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use crate::{
+        eval::{self, FromFn},
+        solution::Individual,
+    };
+
+    use super::*;
+
+    type Identity = FromFn<fn(&i32, &()) -> i32>;
+
+    fn identity() -> Identity {
+        let identity: fn(&i32, &()) -> i32 = |solution, _| *solution;
+        eval::from_fn(identity)
+    }
+
+    // NOTE: This improves the solution by one until it reaches 10, which is a local optimum.
+    struct Climb {
+        calls: usize,
+    }
+
+    impl<P, E> Operator<P, Individual<i32>, E> for Climb
+    where
+        E: Eval<P, i32>,
+    {
+        type Output = ();
+
+        type Error = Infallible;
+
+        fn apply(
+            &mut self,
+            solution: &mut Individual<i32>,
+            problem: &P,
+            eval: &mut E,
+            _input: (),
+        ) -> Result<Self::Output, Self::Error> {
+            self.search(solution, problem, eval).map(|_| ())
+        }
+    }
+
+    impl<P, E> Search<P, Individual<i32>, E> for Climb
+    where
+        E: Eval<P, i32>,
+    {
+        fn search(
+            &mut self,
+            solution: &mut Individual<i32>,
+            _problem: &P,
+            _eval: &mut E,
+        ) -> Result<bool, Self::Error> {
+            self.calls += 1;
+            if **solution < 10 {
+                **solution += 1;
+                Ok(true)
+            } else {
+                Ok(false)
+            }
+        }
+    }
+
+    fn climb() -> UntilLocalOptimum<Climb> {
+        Operator::<(), Individual<i32>, Identity>::until_local_optimum(Climb { calls: 0 })
+    }
+
+    #[test]
+    fn climbs_until_local_optimum() {
+        let mut op = climb();
+        let mut solution = Individual::new(7);
+        assert_eq!(op.search(&mut solution, &(), &mut identity()), Ok(true));
+        assert_eq!((*solution, op.op.calls), (10, 4));
+
+        op.op.calls = 0;
+        assert_eq!(op.search(&mut solution, &(), &mut identity()), Ok(false));
+        assert_eq!((*solution, op.op.calls), (10, 1));
+    }
+
+    #[test]
+    fn stops_after_max_steps() {
+        let mut op = climb().max_steps(2);
+        let mut solution = Individual::new(5);
+        assert_eq!(op.search(&mut solution, &(), &mut identity()), Ok(true));
+        assert_eq!((*solution, op.op.calls), (7, 2));
+
+        // NOTE: Reaching the local optimum within the maximum number of steps still stops early.
+        let mut solution = Individual::new(9);
+        assert_eq!(op.search(&mut solution, &(), &mut identity()), Ok(true));
+        assert_eq!((*solution, op.op.calls), (10, 4));
+
+        let mut op = climb().max_steps(0);
+        assert_eq!(op.search(&mut solution, &(), &mut identity()), Ok(false));
+        assert_eq!(op.op.calls, 0);
     }
 }
@@ -0,0 +1,141 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{eval::Eval, solution::Solution};
+
+use super::Operator;
+
+// NOTE: Operators, stop conditions and acceptance criteria that hold state between applications (such as `Iterations`,
+//       which counts down in place) impl this to return to the state they were constructed in, so that the solvers they
+//       are part of can be run more than once. Combinators forward it to every operator or condition they contain, and
+//       solvers reset themselves at the start of every run.
+//
+//       Every type has to impl this, even if it has nothing to reset, so that no state is ever kept by accident. Values
+//       that update themselves in place without remembering their initial state (such as closures capturing a counter) can
+//       be made resettable using `op::fresh`. Note that RNGs are never reset, since re-running a solver with the same
+//       random numbers is rarely what is wanted.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait Reset {
+    fn reset(&mut self);
+}
+
+// NOTE: Trait objects can only be made of a single (non-auto) trait, so `Box<dyn Operator<...>>` can never be reset.
+//       Operators that impl `Reset` can be boxed as this trait instead (see `Operator::boxed_reset`).
+pub trait DynOperator<P, S, E, In = ()>: Operator<P, S, E, In> + Reset
+where
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+}
+
+impl<T, P, S, E, In> DynOperator<P, S, E, In> for T
+where
+    T: Operator<P, S, E, In> + Reset + ?Sized,
+    S: Solution,
+    E: Eval<P, S::Individual>,
+{
+}
+
+impl<T> Reset for &mut T
+where
+    T: Reset + ?Sized,
+{
+    fn reset(&mut self) {
+        T::reset(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Reset for Box<T>
+where
+    T: Reset + ?Sized,
+{
+    fn reset(&mut self) {
+        T::reset(self)
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L, R> Reset for either::Either<L, R>
+where
+    L: Reset,
+    R: Reset,
+{
+    fn reset(&mut self) {
+        match self {
+            Self::Left(left) => left.reset(),
+            Self::Right(right) => right.reset(),
+        }
+    }
+}
+
+impl<T> Reset for Option<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        if let Some(inner) = self {
+            inner.reset();
+        }
+    }
+}
+
+impl<T> Reset for [T]
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.iter_mut().for_each(T::reset);
+    }
+}
+
+impl<T, const N: usize> Reset for [T; N]
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.iter_mut().for_each(T::reset);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Reset for Vec<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.iter_mut().for_each(T::reset);
+    }
+}
+
+impl Reset for () {
+    fn reset(&mut self) {}
+}
+
+macro_rules! impl_for_tuples {
+    ($(($($name:ident),+)),+ $(,)?) => {
+        $(
+            impl<$($name,)+> Reset for ($($name,)+)
+            where
+                $($name: Reset,)+
+            {
+                #[allow(non_snake_case)]
+                fn reset(&mut self) {
+                    let ($($name,)+) = self;
+                    $($name.reset();)+
+                }
+            }
+        )+
+    };
+}
+
+impl_for_tuples!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, F),
+    (A, B, C, D, F, G),
+    (A, B, C, D, F, G, H),
+    (A, B, C, D, F, G, H, I),
+);
@@ -1,17 +1,33 @@
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
-use crate::{eval::Eval, solution::Solution};
+use rand::Rng;
+
+use crate::{
+    eval::Eval,
+    solution::{Move, Solution},
+};
 
 use super::Operator;
 
+mod climb;
+pub use climb::{BestImprovement, FirstImprovement, RandomImprovement};
+
+mod incremental;
+pub use incremental::Incremental;
+
+#[cfg(feature = "alloc")]
+pub mod tabu;
+
 // TODO: Add `#[diagnostic::on_unimplemented]`
 pub trait Search<P, S, E>: Operator<P, S, E>
 where
     S: Solution,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error>;
+    // NOTE: This returns whether the search improved the solution, which lets combinators such as `UntilLocalOptimum`
+    //       detect when a local optimum has been reached.
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error>;
 }
 
 impl<P, S, E> Search<P, S, E> for ()
@@ -24,8 +40,8 @@ where
         _solution: &mut S,
         _problem: &P,
         _eval: &mut E,
-    ) -> Result<(), Self::Error> {
-        Ok(())
+    ) -> Result<bool, Self::Error> {
+        Ok(false)
     }
 }
 
@@ -35,7 +51,7 @@ where
     S: Solution,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
         T::search(self, solution, problem, eval)
     }
 }
@@ -47,7 +63,7 @@ where
     S: Solution,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
         T::search(self, solution, problem, eval)
     }
 }
@@ -58,11 +74,11 @@ where
     S: Solution,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
-        if let Some(op) = self {
-            op.search(solution, problem, eval)?;
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
+        match self {
+            Some(op) => op.search(solution, problem, eval),
+            None => Ok(false),
         }
-        Ok(())
     }
 }
 
@@ -74,10 +90,75 @@ where
     S: Solution,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
         match self {
             Self::Left(left) => left.search(solution, problem, eval),
             Self::Right(right) => right.search(solution, problem, eval),
         }
     }
 }
+
+// NOTE: A neighbourhood defines the moves that lead from a solution to its neighbouring solutions. The iterator returned by
+//       `moves` cannot borrow the solution, since search operators apply and undo moves while iterating over them.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait Neighbourhood<P, S> {
+    type Move: Move<S>;
+
+    type Moves: Iterator<Item = Self::Move>;
+
+    fn moves(&mut self, solution: &S, problem: &P) -> Self::Moves;
+
+    // NOTE: The default impl picks a move uniformly at random by enumerating the neighbourhood twice, so it should be
+    //       overridden if moves can be sampled more efficiently.
+    fn sample<R>(&mut self, solution: &S, problem: &P, rng: &mut R) -> Option<Self::Move>
+    where
+        R: Rng + ?Sized,
+    {
+        let len = self.moves(solution, problem).count();
+        if len == 0 {
+            return None;
+        }
+        self.moves(solution, problem).nth(rng.random_range(0..len))
+    }
+}
+
+impl<N, P, S> Neighbourhood<P, S> for &mut N
+where
+    N: Neighbourhood<P, S> + ?Sized,
+{
+    type Move = N::Move;
+
+    type Moves = N::Moves;
+
+    fn moves(&mut self, solution: &S, problem: &P) -> Self::Moves {
+        N::moves(self, solution, problem)
+    }
+
+    fn sample<R>(&mut self, solution: &S, problem: &P, rng: &mut R) -> Option<Self::Move>
+    where
+        R: Rng + ?Sized,
+    {
+        N::sample(self, solution, problem, rng)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<N, P, S> Neighbourhood<P, S> for Box<N>
+where
+    N: Neighbourhood<P, S> + ?Sized,
+{
+    type Move = N::Move;
+
+    type Moves = N::Moves;
+
+    fn moves(&mut self, solution: &S, problem: &P) -> Self::Moves {
+        N::moves(self, solution, problem)
+    }
+
+    fn sample<R>(&mut self, solution: &S, problem: &P, rng: &mut R) -> Option<Self::Move>
+    where
+        R: Rng + ?Sized,
+    {
+        N::sample(self, solution, problem, rng)
+    }
+}
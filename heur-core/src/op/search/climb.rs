@@ -0,0 +1,395 @@
+use core::convert::Infallible;
+
+use rand::Rng;
+
+use crate::{
+    eval::{Delta, Eval, Full},
+    op::Reset,
+    solution::Individual,
+};
+
+use super::{Incremental, Neighbourhood, Operator, Search};
+
+// NOTE: This applies the first move that improves on the current solution, in the order given by the neighbourhood.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct FirstImprovement<N> {
+    neighbourhood: N,
+}
+
+impl<N> FirstImprovement<N> {
+    pub fn new(neighbourhood: N) -> Self {
+        Self { neighbourhood }
+    }
+
+    pub fn neighbourhood(&self) -> &N {
+        &self.neighbourhood
+    }
+
+    pub fn neighbourhood_mut(&mut self) -> &mut N {
+        &mut self.neighbourhood
+    }
+
+    pub fn incremental(self) -> Incremental<Self> {
+        Incremental(self)
+    }
+
+    fn climb<P, S, E>(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> bool
+    where
+        N: Neighbourhood<P, S>,
+        E: Delta<P, S, N::Move>,
+    {
+        let direction = eval.direction();
+        let objective = eval.eval(solution, problem);
+
+        let next = self.neighbourhood.moves(solution, problem).find(|mv| {
+            let neighbour = eval.eval_move(solution, problem, &objective, mv);
+            direction.rank(&neighbour) > direction.rank(&objective)
+        });
+
+        let improved = next.is_some();
+        if let Some(mv) = next {
+            eval.apply_move(solution, problem, &mv);
+        }
+
+        improved
+    }
+}
+
+impl<N, P, S, E> Operator<P, Individual<S>, E> for FirstImprovement<N>
+where
+    N: Neighbourhood<P, S>,
+    E: Eval<P, S>,
+{
+    type Output = ();
+
+    type Error = Infallible;
+
+    fn apply(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.search(solution, problem, eval).map(|_| ())
+    }
+}
+
+impl<N, P, S, E> Search<P, Individual<S>, E> for FirstImprovement<N>
+where
+    N: Neighbourhood<P, S>,
+    E: Eval<P, S>,
+{
+    fn search(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.climb(solution, problem, &mut Full(eval)))
+    }
+}
+
+impl<N, P, S, E> Operator<P, Individual<S>, E> for Incremental<FirstImprovement<N>>
+where
+    N: Neighbourhood<P, S>,
+    E: Delta<P, S, N::Move>,
+{
+    type Output = ();
+
+    type Error = Infallible;
+
+    fn apply(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.search(solution, problem, eval).map(|_| ())
+    }
+}
+
+impl<N, P, S, E> Search<P, Individual<S>, E> for Incremental<FirstImprovement<N>>
+where
+    N: Neighbourhood<P, S>,
+    E: Delta<P, S, N::Move>,
+{
+    fn search(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.0.climb(solution, problem, eval))
+    }
+}
+
+// NOTE: This evaluates the entire neighbourhood and applies the best move, but only if it improves on the current solution.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct BestImprovement<N> {
+    neighbourhood: N,
+}
+
+impl<N> BestImprovement<N> {
+    pub fn new(neighbourhood: N) -> Self {
+        Self { neighbourhood }
+    }
+
+    pub fn neighbourhood(&self) -> &N {
+        &self.neighbourhood
+    }
+
+    pub fn neighbourhood_mut(&mut self) -> &mut N {
+        &mut self.neighbourhood
+    }
+
+    pub fn incremental(self) -> Incremental<Self> {
+        Incremental(self)
+    }
+
+    fn climb<P, S, E>(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> bool
+    where
+        N: Neighbourhood<P, S>,
+        E: Delta<P, S, N::Move>,
+    {
+        let direction = eval.direction();
+        let objective = eval.eval(solution, problem);
+
+        let mut best = None;
+        for mv in self.neighbourhood.moves(solution, problem) {
+            let neighbour = eval.eval_move(solution, problem, &objective, &mv);
+            let best_objective = best.as_ref().map_or(&objective, |(_, best)| best);
+            if direction.rank(&neighbour) > direction.rank(best_objective) {
+                best = Some((mv, neighbour));
+            }
+        }
+
+        let improved = best.is_some();
+        if let Some((mv, _)) = best {
+            eval.apply_move(solution, problem, &mv);
+        }
+
+        improved
+    }
+}
+
+impl<N, P, S, E> Operator<P, Individual<S>, E> for BestImprovement<N>
+where
+    N: Neighbourhood<P, S>,
+    E: Eval<P, S>,
+{
+    type Output = ();
+
+    type Error = Infallible;
+
+    fn apply(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.search(solution, problem, eval).map(|_| ())
+    }
+}
+
+impl<N, P, S, E> Search<P, Individual<S>, E> for BestImprovement<N>
+where
+    N: Neighbourhood<P, S>,
+    E: Eval<P, S>,
+{
+    fn search(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.climb(solution, problem, &mut Full(eval)))
+    }
+}
+
+impl<N, P, S, E> Operator<P, Individual<S>, E> for Incremental<BestImprovement<N>>
+where
+    N: Neighbourhood<P, S>,
+    E: Delta<P, S, N::Move>,
+{
+    type Output = ();
+
+    type Error = Infallible;
+
+    fn apply(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.search(solution, problem, eval).map(|_| ())
+    }
+}
+
+impl<N, P, S, E> Search<P, Individual<S>, E> for Incremental<BestImprovement<N>>
+where
+    N: Neighbourhood<P, S>,
+    E: Delta<P, S, N::Move>,
+{
+    fn search(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.0.climb(solution, problem, eval))
+    }
+}
+
+// NOTE: This samples up to `samples` random moves from the neighbourhood and applies the first one that improves on the
+//       current solution. It is useful for neighbourhoods that are too large to enumerate.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct RandomImprovement<N, R> {
+    neighbourhood: N,
+    samples: usize,
+    rng: R,
+}
+
+impl<N, R> RandomImprovement<N, R> {
+    pub fn new(neighbourhood: N, samples: usize, rng: R) -> Self {
+        Self {
+            neighbourhood,
+            samples,
+            rng,
+        }
+    }
+
+    pub fn neighbourhood(&self) -> &N {
+        &self.neighbourhood
+    }
+
+    pub fn neighbourhood_mut(&mut self) -> &mut N {
+        &mut self.neighbourhood
+    }
+
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    pub fn incremental(self) -> Incremental<Self> {
+        Incremental(self)
+    }
+
+    fn climb<P, S, E>(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> bool
+    where
+        N: Neighbourhood<P, S>,
+        E: Delta<P, S, N::Move>,
+        R: Rng,
+    {
+        let direction = eval.direction();
+        let objective = eval.eval(solution, problem);
+
+        for _ in 0..self.samples {
+            let Some(mv) = self.neighbourhood.sample(solution, problem, &mut self.rng) else {
+                break;
+            };
+            let neighbour = eval.eval_move(solution, problem, &objective, &mv);
+            if direction.rank(&neighbour) > direction.rank(&objective) {
+                eval.apply_move(solution, problem, &mv);
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+impl<N, P, S, E, R> Operator<P, Individual<S>, E> for RandomImprovement<N, R>
+where
+    N: Neighbourhood<P, S>,
+    E: Eval<P, S>,
+    R: Rng,
+{
+    type Output = ();
+
+    type Error = Infallible;
+
+    fn apply(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.search(solution, problem, eval).map(|_| ())
+    }
+}
+
+impl<N, P, S, E, R> Search<P, Individual<S>, E> for RandomImprovement<N, R>
+where
+    N: Neighbourhood<P, S>,
+    E: Eval<P, S>,
+    R: Rng,
+{
+    fn search(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.climb(solution, problem, &mut Full(eval)))
+    }
+}
+
+impl<N, P, S, E, R> Operator<P, Individual<S>, E> for Incremental<RandomImprovement<N, R>>
+where
+    N: Neighbourhood<P, S>,
+    E: Delta<P, S, N::Move>,
+    R: Rng,
+{
+    type Output = ();
+
+    type Error = Infallible;
+
+    fn apply(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.search(solution, problem, eval).map(|_| ())
+    }
+}
+
+impl<N, P, S, E, R> Search<P, Individual<S>, E> for Incremental<RandomImprovement<N, R>>
+where
+    N: Neighbourhood<P, S>,
+    E: Delta<P, S, N::Move>,
+    R: Rng,
+{
+    fn search(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.0.climb(solution, problem, eval))
+    }
+}
+
+impl<N> Reset for FirstImprovement<N> {
+    fn reset(&mut self) {}
+}
+
+impl<N> Reset for BestImprovement<N> {
+    fn reset(&mut self) {}
+}
+
+impl<N, R> Reset for RandomImprovement<N, R> {
+    fn reset(&mut self) {}
+}
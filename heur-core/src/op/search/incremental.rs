@@ -0,0 +1,31 @@
+use crate::op::Reset;
+
+// NOTE: Search operators that evaluate moves (such as `BestImprovement` and `TabuSearch`) do so by applying each move and
+//       evaluating the solution from scratch, which works with any evaluator. Wrapping them in this makes them use the
+//       evaluator's `Delta` impl instead, which can evaluate and apply moves incrementally (see `Eval::with_delta`).
+//       Create it using the `incremental` method of the wrapped operator.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Incremental<T>(pub(super) T);
+
+impl<T> AsRef<T> for Incremental<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsMut<T> for Incremental<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> Reset for Incremental<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
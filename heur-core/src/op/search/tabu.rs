@@ -0,0 +1,401 @@
+use core::convert::Infallible;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use crate::{
+    eval::{Delta, Direction, Eval, Full},
+    op::Reset,
+    solution::Individual,
+};
+
+use super::{Incremental, Neighbourhood, Operator, Search};
+
+mod list;
+pub use list::TabuList;
+
+mod tenure;
+pub use tenure::{FixedTenure, RandomTenure};
+
+mod aspiration;
+pub use aspiration::{BetterThanBest, NoAspiration};
+
+// NOTE: The attribute of a move is what gets stored in the tabu list after the move is applied. Any later move with the
+//       same attribute is then tabu until its tenure expires. For example, the attribute of flipping a bit could be the
+//       index of the bit, which forbids flipping it back for a while.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait MoveAttribute {
+    type Attribute: Ord;
+
+    fn attribute(&self) -> Self::Attribute;
+}
+
+impl<M> MoveAttribute for &M
+where
+    M: MoveAttribute + ?Sized,
+{
+    type Attribute = M::Attribute;
+
+    fn attribute(&self) -> Self::Attribute {
+        M::attribute(self)
+    }
+}
+
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait Tenure {
+    // NOTE: This is called once for every move added to the tabu list, and returns how many iterations it stays tabu for.
+    #[must_use]
+    fn tenure(&mut self) -> usize;
+}
+
+impl<T> Tenure for &mut T
+where
+    T: Tenure + ?Sized,
+{
+    fn tenure(&mut self) -> usize {
+        T::tenure(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Tenure for Box<T>
+where
+    T: Tenure + ?Sized,
+{
+    fn tenure(&mut self) -> usize {
+        T::tenure(self)
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L, R> Tenure for either::Either<L, R>
+where
+    L: Tenure,
+    R: Tenure,
+{
+    fn tenure(&mut self) -> usize {
+        match self {
+            Self::Left(left) => left.tenure(),
+            Self::Right(right) => right.tenure(),
+        }
+    }
+}
+
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait Aspiration<O> {
+    // NOTE: Tabu moves are still admissible if this returns `true` for the objective value they lead to, given the best
+    //       objective value found so far.
+    #[must_use]
+    fn aspire(&mut self, objective: &O, best: &O, direction: Direction) -> bool;
+}
+
+impl<T, O> Aspiration<O> for &mut T
+where
+    T: Aspiration<O> + ?Sized,
+{
+    fn aspire(&mut self, objective: &O, best: &O, direction: Direction) -> bool {
+        T::aspire(self, objective, best, direction)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, O> Aspiration<O> for Box<T>
+where
+    T: Aspiration<O> + ?Sized,
+{
+    fn aspire(&mut self, objective: &O, best: &O, direction: Direction) -> bool {
+        T::aspire(self, objective, best, direction)
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L, R, O> Aspiration<O> for either::Either<L, R>
+where
+    L: Aspiration<O>,
+    R: Aspiration<O>,
+{
+    fn aspire(&mut self, objective: &O, best: &O, direction: Direction) -> bool {
+        match self {
+            Self::Left(left) => left.aspire(objective, best, direction),
+            Self::Right(right) => right.aspire(objective, best, direction),
+        }
+    }
+}
+
+// NOTE: Every call to `search` performs a single tabu search iteration, moving to the best admissible neighbouring solution
+//       even if it is worse than the current one. This is usually combined with `Operator::repeat_until` and
+//       `RepeatUntil::keep_best`, since the final solution is not necessarily the best one found.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct TabuSearch<N, T, A, K, O> {
+    neighbourhood: N,
+    tenure: T,
+    aspiration: A,
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(deserialize = "TabuList<K>: serde::Deserialize<'de>"))
+    )]
+    list: TabuList<K>,
+    best: Option<O>,
+}
+
+impl<N, T, A, K, O> TabuSearch<N, T, A, K, O> {
+    pub fn new(neighbourhood: N, tenure: T, aspiration: A) -> Self {
+        Self {
+            neighbourhood,
+            tenure,
+            aspiration,
+            list: TabuList::new(),
+            best: None,
+        }
+    }
+
+    pub fn neighbourhood(&self) -> &N {
+        &self.neighbourhood
+    }
+
+    pub fn neighbourhood_mut(&mut self) -> &mut N {
+        &mut self.neighbourhood
+    }
+
+    pub fn tabu_list(&self) -> &TabuList<K> {
+        &self.list
+    }
+
+    pub fn best_objective(&self) -> Option<&O> {
+        self.best.as_ref()
+    }
+
+    pub fn incremental(self) -> Incremental<Self> {
+        Incremental(self)
+    }
+
+    fn step<P, S, E>(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> bool
+    where
+        N: Neighbourhood<P, S, Move: MoveAttribute<Attribute = K>>,
+        T: Tenure,
+        A: Aspiration<O>,
+        E: Delta<P, S, N::Move, Objective = O>,
+        K: Ord,
+        O: PartialOrd + Clone,
+    {
+        let direction = eval.direction();
+        let objective = eval.eval(solution, problem);
+
+        // NOTE: The solution may have been changed by other operators since the last iteration, so we need to check
+        //       whether it is better than the best one found so far.
+        if (self.best.as_ref()).is_none_or(|best| direction.rank(&objective) > direction.rank(best))
+        {
+            self.best = Some(objective.clone());
+        }
+        let best = self.best.as_ref().unwrap(); // PANICS: We know that there is a best objective value from above
+
+        // Find the best neighbouring solution that is either not tabu or satisfies the aspiration criterion
+        let mut next = None;
+        for mv in self.neighbourhood.moves(solution, problem) {
+            let neighbour = eval.eval_move(solution, problem, &objective, &mv);
+            let better = (next.as_ref())
+                .is_none_or(|(_, next)| direction.rank(&neighbour) > direction.rank(next));
+            if better
+                && (!self.list.is_tabu(&mv.attribute())
+                    || self.aspiration.aspire(&neighbour, best, direction))
+            {
+                next = Some((mv, neighbour));
+            }
+        }
+
+        let improved = match next {
+            Some((mv, neighbour)) => {
+                self.list.insert(mv.attribute(), self.tenure.tenure());
+                eval.apply_move(solution, problem, &mv);
+
+                let improved = direction.rank(&neighbour) > direction.rank(&objective);
+                if direction.rank(&neighbour) > direction.rank(best) {
+                    self.best = Some(neighbour);
+                }
+                improved
+            },
+            None => false,
+        };
+        self.list.advance();
+
+        improved
+    }
+}
+
+impl<N, T, A, K, O> Reset for TabuSearch<N, T, A, K, O> {
+    fn reset(&mut self) {
+        self.list.clear();
+        self.best = None;
+    }
+}
+
+impl<N, T, A, P, S, E> Operator<P, Individual<S>, E>
+    for TabuSearch<N, T, A, <N::Move as MoveAttribute>::Attribute, E::Objective>
+where
+    N: Neighbourhood<P, S, Move: MoveAttribute>,
+    T: Tenure,
+    A: Aspiration<E::Objective>,
+    E: Eval<P, S, Objective: Clone>,
+{
+    type Output = ();
+
+    type Error = Infallible;
+
+    fn apply(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.search(solution, problem, eval).map(|_| ())
+    }
+}
+
+impl<N, T, A, P, S, E> Search<P, Individual<S>, E>
+    for TabuSearch<N, T, A, <N::Move as MoveAttribute>::Attribute, E::Objective>
+where
+    N: Neighbourhood<P, S, Move: MoveAttribute>,
+    T: Tenure,
+    A: Aspiration<E::Objective>,
+    E: Eval<P, S, Objective: Clone>,
+{
+    fn search(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.step(solution, problem, &mut Full(eval)))
+    }
+}
+
+impl<N, T, A, P, S, E> Operator<P, Individual<S>, E>
+    for Incremental<TabuSearch<N, T, A, <N::Move as MoveAttribute>::Attribute, E::Objective>>
+where
+    N: Neighbourhood<P, S, Move: MoveAttribute>,
+    T: Tenure,
+    A: Aspiration<E::Objective>,
+    E: Delta<P, S, N::Move, Objective: Clone>,
+{
+    type Output = ();
+
+    type Error = Infallible;
+
+    fn apply(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.search(solution, problem, eval).map(|_| ())
+    }
+}
+
+impl<N, T, A, P, S, E> Search<P, Individual<S>, E>
+    for Incremental<TabuSearch<N, T, A, <N::Move as MoveAttribute>::Attribute, E::Objective>>
+where
+    N: Neighbourhood<P, S, Move: MoveAttribute>,
+    T: Tenure,
+    A: Aspiration<E::Objective>,
+    E: Delta<P, S, N::Move, Objective: Clone>,
+{
+    fn search(
+        &mut self,
+        solution: &mut Individual<S>,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.0.step(solution, problem, eval))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{iter::Map, ops::Range};
+
+    use crate::{eval, solution::Move};
+
+    use super::*;
+
+    struct Flip(usize);
+
+    impl Move<[bool; 3]> for Flip {
+        fn apply(&self, solution: &mut [bool; 3]) {
+            solution[self.0] = !solution[self.0];
+        }
+
+        fn undo(&self, solution: &mut [bool; 3]) {
+            self.apply(solution);
+        }
+    }
+
+    impl MoveAttribute for Flip {
+        type Attribute = usize;
+
+        fn attribute(&self) -> Self::Attribute {
+            self.0
+        }
+    }
+
+    struct Flips;
+
+    impl Neighbourhood<(), [bool; 3]> for Flips {
+        type Move = Flip;
+
+        type Moves = Map<Range<usize>, fn(usize) -> Flip>;
+
+        fn moves(&mut self, _solution: &[bool; 3], _problem: &()) -> Self::Moves {
+            (0..3).map(Flip)
+        }
+    }
+
+    // NOTE: Later bits are worth more, so every flip leads to a different objective value.
+    fn weighted(solution: &[bool; 3], _problem: &()) -> usize {
+        (solution.iter().enumerate())
+            .filter(|(_, bit)| **bit)
+            .map(|(idx, _)| idx + 1)
+            .sum()
+    }
+
+    #[test]
+    fn moves_away_from_local_optimum_without_moving_back() {
+        let mut eval = eval::from_fn(weighted);
+        let mut tabu = TabuSearch::new(Flips, FixedTenure::new(10), NoAspiration::new());
+        let mut solution = Individual::new([true; 3]);
+
+        assert_eq!(tabu.search(&mut solution, &(), &mut eval), Ok(false));
+        assert_eq!(*solution, [false, true, true]);
+        assert!(tabu.tabu_list().is_tabu(&0));
+
+        assert_eq!(tabu.search(&mut solution, &(), &mut eval), Ok(false));
+        assert_eq!(*solution, [false, false, true]);
+        assert_eq!(tabu.best_objective(), Some(&6));
+    }
+
+    #[test]
+    fn aspiration_admits_tabu_moves_to_new_best() {
+        fn run<A>(aspiration: A) -> [bool; 3]
+        where
+            A: Aspiration<usize>,
+        {
+            let mut eval = eval::from_fn(weighted);
+            let mut tabu = TabuSearch::new(Flips, FixedTenure::new(10), aspiration).incremental();
+            let mut solution = Individual::new([false; 3]);
+
+            assert_eq!(tabu.search(&mut solution, &(), &mut eval), Ok(true));
+            assert_eq!(*solution, [false, false, true]);
+
+            // Flipping the last bit back is now tabu, even though it leads to a new best solution from here
+            *solution = [true, true, false];
+            tabu.search(&mut solution, &(), &mut eval).unwrap();
+            *solution
+        }
+
+        assert_eq!(run(NoAspiration::new()), [false, true, false]);
+        assert_eq!(run(BetterThanBest::new()), [true, true, true]);
+    }
+}
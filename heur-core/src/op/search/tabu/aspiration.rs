@@ -0,0 +1,41 @@
+use crate::eval::Direction;
+
+use super::Aspiration;
+
+// NOTE: This is the most common aspiration criterion, which allows tabu moves that lead to a new best solution.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct BetterThanBest;
+
+impl BetterThanBest {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<O> Aspiration<O> for BetterThanBest
+where
+    O: PartialOrd,
+{
+    fn aspire(&mut self, objective: &O, best: &O, direction: Direction) -> bool {
+        direction.rank(objective) > direction.rank(best)
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct NoAspiration;
+
+impl NoAspiration {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<O> Aspiration<O> for NoAspiration {
+    fn aspire(&mut self, _objective: &O, _best: &O, _direction: Direction) -> bool {
+        false
+    }
+}
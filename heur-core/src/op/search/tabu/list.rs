@@ -0,0 +1,78 @@
+use alloc::collections::BTreeMap;
+
+// NOTE: This maps the attributes of recently applied moves to the last iteration they are tabu for. Expired entries are
+//       removed whenever the iteration advances, so the list never holds more entries than the longest tenure.
+//
+//       `serde` only supports `BTreeMap<K, V>` where `K: Ord`, hence the manually specified bounds.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "K: serde::Serialize",
+        deserialize = "K: serde::Deserialize<'de> + Ord",
+    ))
+)]
+#[must_use]
+pub struct TabuList<K> {
+    entries: BTreeMap<K, usize>,
+    iteration: usize,
+}
+
+impl<K> TabuList<K> {
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            iteration: 0,
+        }
+    }
+
+    pub fn iteration(&self) -> usize {
+        self.iteration
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.iteration = 0;
+    }
+}
+
+impl<K> TabuList<K>
+where
+    K: Ord,
+{
+    #[must_use]
+    pub fn is_tabu(&self, attribute: &K) -> bool {
+        self.entries
+            .get(attribute)
+            .is_some_and(|&expiry| expiry >= self.iteration)
+    }
+
+    // NOTE: The attribute stays tabu for the next `tenure` iterations, so a tenure of zero has no effect.
+    pub fn insert(&mut self, attribute: K, tenure: usize) {
+        if tenure > 0 {
+            self.entries
+                .insert(attribute, self.iteration.saturating_add(tenure));
+        }
+    }
+
+    pub fn advance(&mut self) {
+        self.iteration += 1;
+        let iteration = self.iteration;
+        self.entries.retain(|_, &mut expiry| expiry >= iteration);
+    }
+}
+
+impl<K> Default for TabuList<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
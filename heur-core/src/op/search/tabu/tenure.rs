@@ -0,0 +1,51 @@
+use core::ops::RangeInclusive;
+
+use rand::Rng;
+
+use super::Tenure;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct FixedTenure(usize);
+
+impl FixedTenure {
+    pub fn new(tenure: usize) -> Self {
+        Self(tenure)
+    }
+}
+
+impl Tenure for FixedTenure {
+    fn tenure(&mut self) -> usize {
+        self.0
+    }
+}
+
+// NOTE: Randomizing the tenure of every move makes it less likely for the search to cycle between the same solutions.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct RandomTenure<R> {
+    range: RangeInclusive<usize>,
+    rng: R,
+}
+
+impl<R> RandomTenure<R> {
+    pub fn new(range: RangeInclusive<usize>, rng: R) -> Self {
+        Self { range, rng }
+    }
+}
+
+impl<R> Tenure for RandomTenure<R>
+where
+    R: Rng,
+{
+    fn tenure(&mut self) -> usize {
+        // NOTE: We need to check that the range is not empty, because `Rng::random_range` panics on empty ranges.
+        if self.range.is_empty() {
+            0
+        } else {
+            self.rng.random_range(self.range.clone())
+        }
+    }
+}
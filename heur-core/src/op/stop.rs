@@ -3,13 +3,31 @@ use alloc::boxed::Box;
 
 use crate::{eval::Eval, solution::Solution};
 
+use super::{And, Not, Or, Xor};
+
 mod iters;
 pub use iters::Iterations;
 
 mod optimum;
 pub use optimum::Optimum;
 
-// TODO: Add `#[diagnostic::on_unimplemented]` and more combinators
+mod evals;
+pub use evals::Evaluations;
+
+mod stagnation;
+pub use stagnation::{EpsilonStagnation, Stagnation};
+
+mod track;
+pub use track::Track;
+
+#[cfg(feature = "std")]
+mod deadline;
+#[cfg(feature = "std")]
+pub use deadline::{Clock, Deadline, StdClock};
+
+pub use super::logic::{all, and, any, not, or, xor};
+
+// TODO: Add `#[diagnostic::on_unimplemented]`
 pub trait Stop<P, S, E>
 where
     S: Solution,
@@ -17,6 +35,64 @@ where
 {
     #[must_use]
     fn stop(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool;
+
+    // NOTE: Loops call this instead of `stop`, so that combinators can report which of their conditions made them stop as
+    //       `Event::StoppedBy` (see there). Conditions that combine other conditions should override it, and only report
+    //       anything if they stop.
+    #[must_use]
+    fn stop_with_cause(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        self.stop(solution, problem, eval)
+    }
+
+    fn and<F>(self, cond: F) -> And<Self, F>
+    where
+        Self: Sized,
+        F: Stop<P, S, E>,
+    {
+        And {
+            first: self,
+            second: cond,
+        }
+    }
+
+    fn or<F>(self, cond: F) -> Or<Self, F>
+    where
+        Self: Sized,
+        F: Stop<P, S, E>,
+    {
+        Or {
+            first: self,
+            second: cond,
+        }
+    }
+
+    fn xor<F>(self, cond: F) -> Xor<Self, F>
+    where
+        Self: Sized,
+        F: Stop<P, S, E>,
+    {
+        Xor {
+            first: self,
+            second: cond,
+        }
+    }
+
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not(self)
+    }
+
+    fn track(self) -> Track<Self, S::Individual, E::Objective>
+    where
+        Self: Sized,
+    {
+        Track {
+            cond: self,
+            best: None,
+        }
+    }
 }
 
 impl<T, P, S, E> Stop<P, S, E> for &mut T
@@ -28,6 +104,10 @@ where
     fn stop(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
         T::stop(self, solution, problem, eval)
     }
+
+    fn stop_with_cause(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        T::stop_with_cause(self, solution, problem, eval)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -40,6 +120,10 @@ where
     fn stop(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
         T::stop(self, solution, problem, eval)
     }
+
+    fn stop_with_cause(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        T::stop_with_cause(self, solution, problem, eval)
+    }
 }
 
 #[cfg(feature = "either")]
@@ -56,4 +140,11 @@ where
             Self::Right(right) => right.stop(solution, problem, eval),
         }
     }
+
+    fn stop_with_cause(&mut self, solution: &S, problem: &P, eval: &mut E) -> bool {
+        match self {
+            Self::Left(left) => left.stop_with_cause(solution, problem, eval),
+            Self::Right(right) => right.stop_with_cause(solution, problem, eval),
+        }
+    }
 }
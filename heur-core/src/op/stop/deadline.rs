@@ -0,0 +1,180 @@
+use std::time::{Duration, Instant};
+
+use crate::{eval::Eval, op::Reset, solution::Solution};
+
+use super::Stop;
+
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait Clock {
+    type Instant;
+
+    #[must_use]
+    fn now(&mut self) -> Self::Instant;
+
+    #[must_use]
+    fn elapsed(&mut self, since: &Self::Instant) -> Duration;
+}
+
+impl<T> Clock for &mut T
+where
+    T: Clock + ?Sized,
+{
+    type Instant = T::Instant;
+
+    fn now(&mut self) -> Self::Instant {
+        T::now(self)
+    }
+
+    fn elapsed(&mut self, since: &Self::Instant) -> Duration {
+        T::elapsed(self, since)
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[must_use]
+pub struct StdClock;
+
+impl StdClock {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Clock for StdClock {
+    type Instant = Instant;
+
+    fn now(&mut self) -> Self::Instant {
+        Instant::now()
+    }
+
+    fn elapsed(&mut self, since: &Self::Instant) -> Duration {
+        since.elapsed()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[must_use]
+pub struct Deadline<C = StdClock>
+where
+    C: Clock,
+{
+    budget: Duration,
+    // NOTE: We only start timing when `stop` is first called, rather than on construction, so that any time spent between
+    //       constructing the solver and actually running it is not counted.
+    start: Option<C::Instant>,
+    clock: C,
+}
+
+impl Deadline {
+    pub fn new(budget: Duration) -> Self {
+        Self::with_clock(budget, StdClock)
+    }
+}
+
+impl<C> Deadline<C>
+where
+    C: Clock,
+{
+    pub fn with_clock(budget: Duration, clock: C) -> Self {
+        Self {
+            budget,
+            start: None,
+            clock,
+        }
+    }
+
+    #[must_use]
+    pub fn budget(&self) -> Duration {
+        self.budget
+    }
+}
+
+impl<P, S, E, C> Stop<P, S, E> for Deadline<C>
+where
+    S: Solution,
+    E: Eval<P, S::Individual>,
+    C: Clock,
+{
+    fn stop(&mut self, _solution: &S, _problem: &P, _eval: &mut E) -> bool {
+        let start = self.start.get_or_insert_with(|| self.clock.now());
+        self.clock.elapsed(start) >= self.budget
+    }
+}
+
+impl<C> Reset for Deadline<C>
+where
+    C: Clock,
+{
+    fn reset(&mut self) {
+        self.start = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        eval::{self, FromFn},
+        solution::Individual,
+    };
+
+    use super::*;
+
+    type Identity = FromFn<fn(&i32, &()) -> i32>;
+
+    // NOTE: Time only passes when the clock is asked how much of it has elapsed, so every call to `stop` takes one tick.
+    struct Ticks {
+        now: Duration,
+        tick: Duration,
+    }
+
+    impl Clock for Ticks {
+        type Instant = Duration;
+
+        fn now(&mut self) -> Self::Instant {
+            self.now
+        }
+
+        fn elapsed(&mut self, since: &Self::Instant) -> Duration {
+            self.now += self.tick;
+            self.now - *since
+        }
+    }
+
+    fn stops(deadline: &mut Deadline<Ticks>) -> [bool; 4] {
+        let identity: fn(&i32, &()) -> i32 = |solution, _| *solution;
+        let mut eval = eval::from_fn(identity);
+        let mut stops = [false; 4];
+        for stop in &mut stops {
+            *stop = Stop::<(), Individual<i32>, Identity>::stop(
+                deadline,
+                &Individual::new(0),
+                &(),
+                &mut eval,
+            );
+        }
+        stops
+    }
+
+    #[test]
+    fn stops_once_budget_is_used_up() {
+        let clock = Ticks {
+            now: Duration::from_secs(10),
+            tick: Duration::from_millis(1),
+        };
+        let mut deadline = Deadline::with_clock(Duration::from_millis(3), clock);
+        assert_eq!(stops(&mut deadline), [false, false, true, true]);
+    }
+
+    #[test]
+    fn reset_restarts_timing() {
+        let clock = Ticks {
+            now: Duration::ZERO,
+            tick: Duration::from_millis(2),
+        };
+        let mut deadline = Deadline::with_clock(Duration::from_millis(5), clock);
+        assert_eq!(stops(&mut deadline), [false, false, true, true]);
+
+        deadline.reset();
+        assert_eq!(stops(&mut deadline), [false, false, true, true]);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    eval::{Counter, Eval},
+    op::Reset,
+    solution::Solution,
+};
+
+use super::Stop;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Evaluations(pub usize);
+
+impl Evaluations {
+    pub fn new(evals: usize) -> Self {
+        Self(evals)
+    }
+}
+
+impl<P, S, E> Stop<P, S, E> for Evaluations
+where
+    S: Solution,
+    E: Eval<P, S::Individual> + Counter,
+{
+    fn stop(&mut self, _solution: &S, _problem: &P, eval: &mut E) -> bool {
+        eval.evaluations() >= self.0
+    }
+}
+
+impl Reset for Evaluations {
+    fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        eval::{self, Counted, FromFn},
+        solution::{Evaluated, Individual},
+    };
+
+    use super::*;
+
+    type Counting = Counted<FromFn<fn(&i32, &()) -> i32>>;
+
+    #[test]
+    fn stops_once_budget_is_used_up() {
+        let identity: fn(&i32, &()) -> i32 = |solution, _problem| *solution;
+        let mut eval = eval::from_fn(identity).counted();
+        let mut cond = Evaluations::new(2);
+        let solution = Individual::new(0);
+
+        let mut stop = |eval: &mut Counting| {
+            Stop::<(), Individual<i32>, Counting>::stop(&mut cond, &solution, &(), eval)
+        };
+        assert!(!stop(&mut eval));
+        let _ = eval.eval(&solution, &());
+        assert!(!stop(&mut eval));
+        let _ = eval.eval(&solution, &());
+        assert!(stop(&mut eval));
+        let _ = eval.eval(&solution, &());
+        assert!(stop(&mut eval));
+    }
+
+    #[test]
+    fn ignores_cache_hits() {
+        let identity: fn(&i32, &()) -> i32 = |solution, _problem| *solution;
+        let mut eval = eval::from_fn(identity).cached().counted();
+        let mut cond = Evaluations::new(2);
+        let mut solution = Individual::new(Evaluated::new(0));
+
+        for _ in 0..3 {
+            let _ = eval.eval(&solution, &());
+        }
+        assert!(!cond.stop(&solution, &(), &mut eval));
+
+        **solution += 1;
+        let _ = eval.eval(&solution, &());
+        assert!(cond.stop(&solution, &(), &mut eval));
+    }
+}
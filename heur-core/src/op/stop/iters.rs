@@ -1,16 +1,33 @@
 use core::mem;
 
-use crate::{eval::Eval, solution::Solution};
+use crate::{eval::Eval, op::Reset, solution::Solution};
 
 use super::Stop;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
-pub struct Iterations(pub usize);
+pub struct Iterations {
+    iters: usize,
+    remaining: usize,
+}
 
 impl Iterations {
     pub fn new(iters: usize) -> Self {
-        Self(iters)
+        Self {
+            iters,
+            remaining: iters,
+        }
+    }
+
+    #[must_use]
+    pub fn iters(&self) -> usize {
+        self.iters
+    }
+
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.remaining
     }
 }
 
@@ -20,8 +37,14 @@ where
     E: Eval<P, S::Individual>,
 {
     fn stop(&mut self, _solution: &S, _problem: &P, _eval: &mut E) -> bool {
-        let remaining_iters = self.0.saturating_sub(1);
-        let iters = mem::replace(&mut self.0, remaining_iters);
+        let remaining_iters = self.remaining.saturating_sub(1);
+        let iters = mem::replace(&mut self.remaining, remaining_iters);
         iters == 0
     }
 }
+
+impl Reset for Iterations {
+    fn reset(&mut self) {
+        self.remaining = self.iters;
+    }
+}
@@ -1,11 +1,13 @@
 use crate::{
     eval::Eval,
+    op::Reset,
     solution::{Individual, Iter, Population},
 };
 
 use super::Stop;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct Optimum<O>(pub O);
 
@@ -20,7 +22,8 @@ where
     E: Eval<P, S>,
 {
     fn stop(&mut self, solution: &Individual<S>, problem: &P, eval: &mut E) -> bool {
-        eval.eval(solution, problem) >= self.0
+        let direction = eval.direction();
+        direction.rank(&eval.eval(solution, problem)) >= direction.rank(&self.0)
     }
 }
 
@@ -30,8 +33,44 @@ where
     E: Eval<P, S::Individual>,
 {
     fn stop(&mut self, population: &S, problem: &P, eval: &mut E) -> bool {
-        population
-            .iter()
-            .any(|solution| eval.eval(solution, problem) >= self.0)
+        let direction = eval.direction();
+        population.iter().any(|solution| {
+            direction.rank(&eval.eval(solution, problem)) >= direction.rank(&self.0)
+        })
+    }
+}
+
+impl<O> Reset for Optimum<O> {
+    fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::eval::{self, Eval};
+
+    use super::*;
+
+    #[test]
+    fn stops_at_optimum_when_maximizing() {
+        let mut eval = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+
+        assert!(!Optimum(5).stop(&Individual::new(4), &(), &mut eval));
+        assert!(Optimum(5).stop(&Individual::new(5), &(), &mut eval));
+        assert!(Optimum(5).stop(&Individual::new(6), &(), &mut eval));
+        assert!(!Optimum(5).stop(&vec![1, 4], &(), &mut eval));
+        assert!(Optimum(5).stop(&vec![1, 6], &(), &mut eval));
+    }
+
+    #[test]
+    fn stops_at_optimum_when_minimizing() {
+        let mut eval = eval::from_fn(|solution: &i32, _: &()| *solution).minimize();
+
+        assert!(!Optimum(5).stop(&Individual::new(6), &(), &mut eval));
+        assert!(Optimum(5).stop(&Individual::new(5), &(), &mut eval));
+        assert!(Optimum(5).stop(&Individual::new(4), &(), &mut eval));
+        assert!(!Optimum(5).stop(&vec![9, 6], &(), &mut eval));
+        assert!(Optimum(5).stop(&vec![9, 4], &(), &mut eval));
     }
 }
@@ -0,0 +1,254 @@
+use core::ops::Add;
+
+use crate::{
+    eval::{Direction, Eval},
+    op::{Reset, best_of},
+    solution::{Individual, Iter, Population},
+};
+
+use super::Stop;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Stagnation<O> {
+    patience: usize,
+    stagnant_iters: usize,
+    best: Option<O>,
+}
+
+impl<O> Stagnation<O> {
+    pub fn new(patience: usize) -> Self {
+        Self {
+            patience,
+            stagnant_iters: 0,
+            best: None,
+        }
+    }
+
+    // NOTE: Only objective values that are better than the best one by more than `epsilon` count as improvements, which
+    //       requires objective values to be addable (see `EpsilonStagnation`).
+    pub fn with_epsilon(self, epsilon: O) -> EpsilonStagnation<O>
+    where
+        O: Add<Output = O> + Clone,
+    {
+        EpsilonStagnation {
+            stagnation: self,
+            epsilon,
+        }
+    }
+
+    pub fn best(&self) -> Option<&O> {
+        self.best.as_ref()
+    }
+
+    #[must_use]
+    pub fn stagnant_iters(&self) -> usize {
+        self.stagnant_iters
+    }
+
+    fn update<F>(&mut self, objective: Option<O>, improves: F) -> bool
+    where
+        F: FnOnce(&O, &O) -> bool,
+    {
+        let improved = match (&objective, &self.best) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(objective), Some(best)) => improves(objective, best),
+        };
+
+        if improved {
+            self.best = objective;
+            self.stagnant_iters = 0;
+        } else {
+            self.stagnant_iters = self.stagnant_iters.saturating_add(1);
+        }
+
+        self.stagnant_iters >= self.patience
+    }
+}
+
+impl<P, S, E> Stop<P, Individual<S>, E> for Stagnation<E::Objective>
+where
+    E: Eval<P, S>,
+{
+    fn stop(&mut self, solution: &Individual<S>, problem: &P, eval: &mut E) -> bool {
+        let direction = eval.direction();
+        let objective = eval.eval(solution, problem);
+        self.update(Some(objective), |objective, best| {
+            direction.rank(objective) > direction.rank(best)
+        })
+    }
+}
+
+impl<P, S, E> Stop<P, S, E> for Stagnation<E::Objective>
+where
+    S: Population + for<'a> Iter<'a, Item = S::Individual>,
+    E: Eval<P, S::Individual>,
+{
+    fn stop(&mut self, population: &S, problem: &P, eval: &mut E) -> bool {
+        let direction = eval.direction();
+        let objective =
+            best_of(population.iter(), direction, problem, eval).map(|(_, objective)| objective);
+        self.update(objective, |objective, best| {
+            direction.rank(objective) > direction.rank(best)
+        })
+    }
+}
+
+impl<O> Reset for Stagnation<O> {
+    fn reset(&mut self) {
+        self.stagnant_iters = 0;
+        self.best = None;
+    }
+}
+
+// NOTE: This is a `Stagnation` that only counts improvements by more than `epsilon`, which can be created using
+//       `Stagnation::with_epsilon`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct EpsilonStagnation<O> {
+    stagnation: Stagnation<O>,
+    epsilon: O,
+}
+
+impl<O> EpsilonStagnation<O> {
+    pub fn best(&self) -> Option<&O> {
+        self.stagnation.best()
+    }
+
+    #[must_use]
+    pub fn stagnant_iters(&self) -> usize {
+        self.stagnation.stagnant_iters()
+    }
+
+    pub fn epsilon(&self) -> &O {
+        &self.epsilon
+    }
+}
+
+impl<O> EpsilonStagnation<O>
+where
+    O: PartialOrd + Add<Output = O> + Clone,
+{
+    fn update(&mut self, direction: Direction, objective: Option<O>) -> bool {
+        let epsilon = &self.epsilon;
+        self.stagnation
+            .update(objective, |objective, best| match direction {
+                // NOTE: We add the epsilon to the smaller value in both cases rather than subtracting it from the bigger
+                //       one, since subtraction may overflow with unsigned objective values.
+                Direction::Maximize => *objective > best.clone() + epsilon.clone(),
+                Direction::Minimize => objective.clone() + epsilon.clone() < *best,
+            })
+    }
+}
+
+impl<P, S, E> Stop<P, Individual<S>, E> for EpsilonStagnation<E::Objective>
+where
+    E: Eval<P, S, Objective: Add<Output = E::Objective> + Clone>,
+{
+    fn stop(&mut self, solution: &Individual<S>, problem: &P, eval: &mut E) -> bool {
+        let objective = eval.eval(solution, problem);
+        self.update(eval.direction(), Some(objective))
+    }
+}
+
+impl<P, S, E> Stop<P, S, E> for EpsilonStagnation<E::Objective>
+where
+    S: Population + for<'a> Iter<'a, Item = S::Individual>,
+    E: Eval<P, S::Individual, Objective: Add<Output = E::Objective> + Clone>,
+{
+    fn stop(&mut self, population: &S, problem: &P, eval: &mut E) -> bool {
+        let direction = eval.direction();
+        let objective =
+            best_of(population.iter(), direction, problem, eval).map(|(_, objective)| objective);
+        self.update(direction, objective)
+    }
+}
+
+impl<O> Reset for EpsilonStagnation<O> {
+    fn reset(&mut self) {
+        self.stagnation.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eval::{self, FromFn};
+
+    use super::*;
+
+    type Identity = FromFn<fn(&i32, &()) -> i32>;
+
+    fn identity() -> Identity {
+        let identity: fn(&i32, &()) -> i32 = |solution, _| *solution;
+        eval::from_fn(identity)
+    }
+
+    fn stops<T, E>(cond: &mut T, eval: &mut E, objectives: &[i32]) -> [bool; 5]
+    where
+        T: Stop<(), Individual<i32>, E>,
+        E: Eval<(), i32>,
+    {
+        let mut stops = [false; 5];
+        for (stop, objective) in stops.iter_mut().zip(objectives) {
+            *stop = cond.stop(&Individual::new(*objective), &(), eval);
+        }
+        stops
+    }
+
+    #[test]
+    fn stops_after_patience_iterations_without_improvement() {
+        let mut cond = Stagnation::new(2);
+        let mut min = identity().minimize();
+        let stops = stops(&mut cond, &mut min, &[5, 5, 6, 7, 4]);
+        assert_eq!(stops, [false, false, true, true, false]);
+        assert_eq!(cond.best(), Some(&4));
+
+        let mut cond = Stagnation::new(2);
+        let mut max = identity().maximize();
+        for (objective, stop) in [(5, false), (5, false), (6, false), (4, false), (3, true)] {
+            assert_eq!(cond.stop(&Individual::new(objective), &(), &mut max), stop);
+        }
+        assert_eq!(cond.best(), Some(&6));
+    }
+
+    #[test]
+    fn epsilon_ignores_small_improvements() {
+        let mut cond = Stagnation::new(2).with_epsilon(2);
+        let mut min = identity().minimize();
+        let stops = stops(&mut cond, &mut min, &[10, 9, 8, 7, 4]);
+        assert_eq!(stops, [false, false, true, false, false]);
+        assert_eq!((cond.best(), cond.stagnant_iters()), (Some(&4), 0));
+
+        let mut cond = Stagnation::new(2).with_epsilon(2);
+        let mut max = identity().maximize();
+        for (objective, stop) in [(10, false), (12, false), (11, true), (13, false)] {
+            assert_eq!(cond.stop(&Individual::new(objective), &(), &mut max), stop);
+        }
+        assert_eq!(cond.best(), Some(&13));
+    }
+
+    #[test]
+    fn improvement_resets_stagnant_iterations() {
+        let mut cond = Stagnation::new(3);
+        let mut min = identity().minimize();
+        let stops = stops(&mut cond, &mut min, &[5, 6, 6, 4, 7]);
+        assert_eq!(stops, [false; 5]);
+        assert_eq!(cond.stagnant_iters(), 1);
+
+        cond.reset();
+        assert_eq!((cond.best(), cond.stagnant_iters()), (None, 0));
+    }
+
+    #[test]
+    fn population_uses_best_individual() {
+        let mut cond = Stagnation::new(1);
+        let mut min = identity().minimize();
+        assert!(!cond.stop(&[5, 3, 9], &(), &mut min));
+        assert!(cond.stop(&[4, 8, 3], &(), &mut min));
+        assert!(!cond.stop(&[2, 8], &(), &mut min));
+        assert_eq!(cond.best(), Some(&2));
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    eval::{Direction, Eval},
+    observe::Event,
+    op::{Reset, best_of},
+    solution::{Individual, Iter, Population},
+};
+
+use super::Stop;
+
+// NOTE: This keeps a clone of the best solution (or best individual of a population) it has been asked about, and its
+//       objective value, before passing the question on to the inner stop condition. Loops check their stop condition
+//       before every iteration and once more after the last one, so this sees every solution they go through. Every time
+//       the best solution is replaced, this is reported as `Event::NewBest`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Track<F, I, O> {
+    pub(super) cond: F,
+    pub(super) best: Option<(I, O)>,
+}
+
+impl<F, I, O> Track<F, I, O> {
+    pub fn best(&self) -> Option<&I> {
+        self.best.as_ref().map(|(best, _)| best)
+    }
+
+    pub fn best_objective(&self) -> Option<&O> {
+        self.best.as_ref().map(|(_, objective)| objective)
+    }
+
+    pub fn into_best(self) -> Option<(I, O)> {
+        self.best
+    }
+}
+
+impl<F, I, O> AsRef<F> for Track<F, I, O> {
+    fn as_ref(&self) -> &F {
+        &self.cond
+    }
+}
+
+impl<F, I, O> AsMut<F> for Track<F, I, O> {
+    fn as_mut(&mut self) -> &mut F {
+        &mut self.cond
+    }
+}
+
+impl<F, P, S, E> Stop<P, Individual<S>, E> for Track<F, S, E::Objective>
+where
+    F: Stop<P, Individual<S>, E>,
+    S: Clone,
+    E: Eval<P, S>,
+{
+    fn stop(&mut self, solution: &Individual<S>, problem: &P, eval: &mut E) -> bool {
+        let direction = eval.direction();
+        let objective = eval.eval(solution, problem);
+        update(&mut self.best, &**solution, objective, direction, eval);
+        self.cond.stop(solution, problem, eval)
+    }
+
+    fn stop_with_cause(&mut self, solution: &Individual<S>, problem: &P, eval: &mut E) -> bool {
+        let direction = eval.direction();
+        let objective = eval.eval(solution, problem);
+        update(&mut self.best, &**solution, objective, direction, eval);
+        self.cond.stop_with_cause(solution, problem, eval)
+    }
+}
+
+impl<F, P, S, E> Stop<P, S, E> for Track<F, S::Individual, E::Objective>
+where
+    F: Stop<P, S, E>,
+    S: Population<Individual: Clone> + for<'a> Iter<'a, Item = S::Individual>,
+    E: Eval<P, S::Individual>,
+{
+    fn stop(&mut self, population: &S, problem: &P, eval: &mut E) -> bool {
+        let direction = eval.direction();
+        if let Some((solution, objective)) = best_of(population.iter(), direction, problem, eval) {
+            update(&mut self.best, solution, objective, direction, eval);
+        }
+        self.cond.stop(population, problem, eval)
+    }
+
+    fn stop_with_cause(&mut self, population: &S, problem: &P, eval: &mut E) -> bool {
+        let direction = eval.direction();
+        if let Some((solution, objective)) = best_of(population.iter(), direction, problem, eval) {
+            update(&mut self.best, solution, objective, direction, eval);
+        }
+        self.cond.stop_with_cause(population, problem, eval)
+    }
+}
+
+fn update<I, O, P, S, E>(
+    best: &mut Option<(I, O)>,
+    solution: &I,
+    objective: O,
+    direction: Direction,
+    eval: &mut E,
+) where
+    I: Clone,
+    O: PartialOrd,
+    E: Eval<P, S, Objective = O>,
+{
+    let improved = match best {
+        None => true,
+        Some((_, best_objective)) => direction.rank(&objective) > direction.rank(best_objective),
+    };
+    if improved {
+        let (_, objective) = best.insert((solution.clone(), objective));
+        eval.observe(Event::NewBest { objective });
+    }
+}
+
+impl<F, I, O> Reset for Track<F, I, O>
+where
+    F: Reset,
+{
+    fn reset(&mut self) {
+        self.cond.reset();
+        self.best = None;
+    }
+}
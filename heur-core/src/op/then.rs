@@ -1,8 +1,9 @@
-use crate::{Optimize, eval::Eval, solution::Solution};
+use crate::{Optimize, eval::Eval, observe::Event, solution::Solution};
 
-use super::{Operator, init::Init, mutate::Mutate, search::Search};
+use super::{Operator, Reset, init::Init, mutate::Mutate, search::Search};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct Then<T, U> {
     pub(super) first: T,
@@ -28,7 +29,9 @@ where
         _input: (),
     ) -> Result<Self::Output, Self::Error> {
         self.first.apply(solution, problem, eval, ())?;
+        eval.observe(Event::operator_applied(&self.first));
         self.second.apply(solution, problem, eval, ())?;
+        eval.observe(Event::operator_applied(&self.second));
         Ok(())
     }
 }
@@ -42,7 +45,9 @@ where
 {
     fn init(&mut self, problem: &P, eval: &mut E) -> Result<S, Self::Error> {
         let mut solution = self.first.init(problem, eval)?;
+        eval.observe(Event::operator_applied(&self.first));
         self.second.apply(&mut solution, problem, eval, ())?;
+        eval.observe(Event::operator_applied(&self.second));
         Ok(solution)
     }
 
@@ -53,7 +58,9 @@ where
         eval: &mut E,
     ) -> Result<(), Self::Error> {
         self.first.init_into(solution, problem, eval)?;
+        eval.observe(Event::operator_applied(&self.first));
         self.second.apply(solution, problem, eval, ())?;
+        eval.observe(Event::operator_applied(&self.second));
         Ok(())
     }
 }
@@ -67,7 +74,9 @@ where
 {
     fn mutate(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
         self.first.mutate(solution, problem, eval)?;
+        eval.observe(Event::operator_applied(&self.first));
         self.second.mutate(solution, problem, eval)?;
+        eval.observe(Event::operator_applied(&self.second));
         Ok(())
     }
 }
@@ -79,23 +88,96 @@ where
     S: Solution,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
-        self.first.search(solution, problem, eval)?;
-        self.second.search(solution, problem, eval)?;
-        Ok(())
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
+        let first = self.first.search(solution, problem, eval)?;
+        eval.observe(Event::operator_applied(&self.first));
+        let second = self.second.search(solution, problem, eval)?;
+        eval.observe(Event::operator_applied(&self.second));
+        Ok(first || second)
     }
 }
 
 impl<T, U, P, S, E> Optimize<P, S, E> for Then<T, U>
 where
-    T: Init<P, S, E, Output = ()>,
-    U: Operator<P, S, E, Output = (), Error = T::Error>,
+    T: Init<P, S, E, Output = ()> + Reset,
+    U: Operator<P, S, E, Output = (), Error = T::Error> + Reset,
     S: Solution,
     E: Eval<P, S::Individual>,
 {
     type Error = T::Error;
 
     fn optimize(&mut self, problem: &P, eval: &mut E) -> Result<S, Self::Error> {
+        // NOTE: This lets the same solver be run more than once (see `Reset`).
+        self.reset();
         self.init(problem, eval)
     }
 }
+
+impl<T, U> Reset for Then<T, U>
+where
+    T: Reset,
+    U: Reset,
+{
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use crate::{
+        eval,
+        op::{self, Hint, init, stop::Iterations},
+        solution::Individual,
+    };
+
+    use super::*;
+
+    struct Increment;
+
+    impl<P, E> Operator<P, Individual<usize>, E> for Increment
+    where
+        E: Eval<P, usize>,
+    {
+        type Output = ();
+
+        type Error = Infallible;
+
+        fn apply(
+            &mut self,
+            solution: &mut Individual<usize>,
+            _problem: &P,
+            _eval: &mut E,
+            _input: (),
+        ) -> Result<Self::Output, Self::Error> {
+            **solution += 1;
+            Ok(())
+        }
+    }
+
+    impl Reset for Increment {
+        fn reset(&mut self) {}
+    }
+
+    type Identity = eval::FromFn<fn(&usize, &()) -> usize>;
+
+    fn hint<T>(op: T) -> Hint<T, (), Individual<usize>, Identity, ()>
+    where
+        T: Operator<(), Individual<usize>, Identity>,
+    {
+        op::hint(op)
+    }
+
+    #[test]
+    fn second_run_performs_every_iteration_again() {
+        let mut eval: Identity = eval::from_fn(|solution, _problem| *solution);
+        let mut solver =
+            hint(init::from_individual(0)).then(hint(Increment).repeat_until(Iterations::new(5)));
+
+        assert_eq!(*solver.optimize(&(), &mut eval).unwrap(), 5);
+        assert_eq!(*solver.optimize(&(), &mut eval).unwrap(), 5);
+    }
+}
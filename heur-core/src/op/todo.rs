@@ -8,7 +8,7 @@ use core::{
 
 use crate::{eval::Eval, solution::Solution};
 
-use super::{Operator, init::Init, mutate::Mutate, search::Search};
+use super::{Operator, Reset, init::Init, mutate::Mutate, search::Search};
 
 #[must_use]
 pub struct Todo<P, S, E, In = (), Out = (), Err = Infallible>(
@@ -111,7 +111,11 @@ where
         _solution: &mut S,
         _problem: &P,
         _eval: &mut E,
-    ) -> Result<(), Self::Error> {
+    ) -> Result<bool, Self::Error> {
         todo!()
     }
 }
+
+impl<P, S, E, In, Out, Err> Reset for Todo<P, S, E, In, Out, Err> {
+    fn reset(&mut self) {}
+}
@@ -2,9 +2,10 @@ use core::convert::Infallible;
 
 use crate::{eval::Eval, solution::Solution};
 
-use super::{Operator, init::Init, mutate::Mutate, search::Search};
+use super::{Operator, Reset, init::Init, mutate::Mutate, search::Search};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct Unwrapped<T>(pub(super) T);
 
@@ -81,8 +82,16 @@ where
     S: Solution,
     E: Eval<P, S::Individual>,
 {
-    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<(), Self::Error> {
-        self.0.search(solution, problem, eval).unwrap();
-        Ok(())
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
+        Ok(self.0.search(solution, problem, eval).unwrap())
+    }
+}
+
+impl<T> Reset for Unwrapped<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.0.reset();
     }
 }
@@ -0,0 +1,255 @@
+use crate::{eval::Eval, observe::Event, solution::Solution};
+
+use super::{Operator, Reset, accept::Accept, list::OperatorList, search::Search};
+
+// NOTE: This applies the operators in order, moving back to the first one whenever a solution is accepted and on to the
+//       next one otherwise, until none of them produce an acceptable solution. The acceptance criterion should therefore
+//       only accept strictly improving solutions (such as `Improving`), since this may never finish otherwise.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct VariableNeighbourhoodDescent<L, F> {
+    ops: L,
+    cond: F,
+}
+
+impl<L, F> VariableNeighbourhoodDescent<L, F> {
+    pub fn new(ops: L, cond: F) -> Self {
+        Self { ops, cond }
+    }
+
+    fn descend<P, S, E>(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<bool, L::Error>
+    where
+        L: OperatorList<P, S, E, Output = ()>,
+        F: Accept<P, S, E>,
+        S: Solution + Clone,
+        E: Eval<P, S::Individual>,
+    {
+        let mut improved = false;
+        let mut idx = 0;
+        while idx < self.ops.len() {
+            let prev_solution = solution.clone();
+
+            self.ops.apply_nth(idx, solution, problem, eval, ())?;
+            if self.cond.accept(solution, &prev_solution, problem, eval) {
+                eval.observe(Event::Accepted);
+                improved = true;
+                idx = 0;
+            } else {
+                eval.observe(Event::Rejected);
+                *solution = prev_solution;
+                idx += 1;
+            }
+        }
+        Ok(improved)
+    }
+}
+
+impl<L, F, P, S, E> Operator<P, S, E> for VariableNeighbourhoodDescent<L, F>
+where
+    L: OperatorList<P, S, E, Output = ()>,
+    F: Accept<P, S, E>,
+    S: Solution + Clone,
+    E: Eval<P, S::Individual>,
+{
+    type Output = ();
+
+    type Error = L::Error;
+
+    fn apply(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.descend(solution, problem, eval).map(|_| ())
+    }
+}
+
+impl<L, F, P, S, E> Search<P, S, E> for VariableNeighbourhoodDescent<L, F>
+where
+    L: OperatorList<P, S, E, Output = ()>,
+    F: Accept<P, S, E>,
+    S: Solution + Clone,
+    E: Eval<P, S::Individual>,
+{
+    fn search(&mut self, solution: &mut S, problem: &P, eval: &mut E) -> Result<bool, Self::Error> {
+        self.descend(solution, problem, eval)
+    }
+}
+
+// NOTE: Every application performs a single step of variable neighbourhood search - the solution is perturbed using the
+//       current shaking operator, improved using the local search operator, and then accepted or rejected. Accepting it
+//       moves back to the first shaking operator, while rejecting it moves on to the next one (wrapping around after the
+//       last one). This is usually combined with `Operator::repeat_until`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct VariableNeighbourhoodSearch<L, T, F> {
+    shakes: L,
+    local_search: T,
+    cond: F,
+    idx: usize,
+}
+
+impl<L, T, F> VariableNeighbourhoodSearch<L, T, F> {
+    pub fn new(shakes: L, local_search: T, cond: F) -> Self {
+        Self {
+            shakes,
+            local_search,
+            cond,
+            idx: 0,
+        }
+    }
+
+    pub fn neighbourhood(&self) -> usize {
+        self.idx
+    }
+}
+
+impl<L, T, F, P, S, E> Operator<P, S, E> for VariableNeighbourhoodSearch<L, T, F>
+where
+    L: OperatorList<P, S, E, Output = ()>,
+    T: Operator<P, S, E, Output = (), Error = L::Error>,
+    F: Accept<P, S, E>,
+    S: Solution + Clone,
+    E: Eval<P, S::Individual>,
+{
+    type Output = ();
+
+    type Error = L::Error;
+
+    fn apply(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        let len = self.shakes.len();
+        if len == 0 {
+            return Ok(());
+        }
+        // NOTE: The list of shaking operators may have shrunk since the last application.
+        if self.idx >= len {
+            self.idx = 0;
+        }
+
+        let prev_solution = solution.clone();
+
+        self.shakes
+            .apply_nth(self.idx, solution, problem, eval, ())?;
+        eval.observe(Event::list_operator_applied(&self.shakes, self.idx));
+        self.local_search.apply(solution, problem, eval, ())?;
+        eval.observe(Event::operator_applied(&self.local_search));
+
+        if self.cond.accept(solution, &prev_solution, problem, eval) {
+            eval.observe(Event::Accepted);
+            self.idx = 0;
+        } else {
+            eval.observe(Event::Rejected);
+            *solution = prev_solution;
+            self.idx = (self.idx + 1) % len;
+        }
+
+        Ok(())
+    }
+}
+
+impl<L, F> Reset for VariableNeighbourhoodDescent<L, F>
+where
+    L: Reset,
+    F: Reset,
+{
+    fn reset(&mut self) {
+        self.ops.reset();
+        self.cond.reset();
+    }
+}
+
+impl<L, T, F> Reset for VariableNeighbourhoodSearch<L, T, F>
+where
+    L: Reset,
+    T: Reset,
+    F: Reset,
+{
+    fn reset(&mut self) {
+        self.shakes.reset();
+        self.local_search.reset();
+        self.cond.reset();
+        self.idx = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use crate::{eval, op::accept::Improving, solution::Individual};
+
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    struct Add(i32);
+
+    impl<P, E> Operator<P, Individual<i32>, E> for Add
+    where
+        E: Eval<P, i32>,
+    {
+        type Output = ();
+
+        type Error = Infallible;
+
+        fn apply(
+            &mut self,
+            solution: &mut Individual<i32>,
+            _problem: &P,
+            _eval: &mut E,
+            _input: (),
+        ) -> Result<Self::Output, Self::Error> {
+            **solution += self.0;
+            Ok(())
+        }
+    }
+
+    fn distance(solution: &i32, _problem: &()) -> i32 {
+        -(solution - 7).abs()
+    }
+
+    #[test]
+    fn descent_returns_to_first_neighbourhood_after_improving() {
+        let mut eval = eval::from_fn(distance);
+        let mut vnd = VariableNeighbourhoodDescent::new([Add(5), Add(1)], Improving::new());
+        let mut solution = Individual::new(0);
+
+        // 0 -> 5 by the first operator, then 5 -> 6 -> 7 by the second one with the first one failing in between
+        assert_eq!(vnd.search(&mut solution, &(), &mut eval), Ok(true));
+        assert_eq!(*solution, 7);
+        assert_eq!(vnd.search(&mut solution, &(), &mut eval), Ok(false));
+        assert_eq!(*solution, 7);
+    }
+
+    #[test]
+    fn search_moves_to_next_neighbourhood_after_rejecting() {
+        let mut eval = eval::from_fn(distance);
+        let mut vns = VariableNeighbourhoodSearch::new([Add(2), Add(-3)], Add(0), Improving::new());
+        let mut solution = Individual::new(0);
+
+        for expected in [2, 4, 6] {
+            vns.apply(&mut solution, &(), &mut eval, ()).unwrap();
+            assert_eq!((*solution, vns.neighbourhood()), (expected, 0));
+        }
+
+        // Neither 8 nor 3 are closer to 7 than 6, so both are rejected and the search wraps around
+        vns.apply(&mut solution, &(), &mut eval, ()).unwrap();
+        assert_eq!((*solution, vns.neighbourhood()), (6, 1));
+        vns.apply(&mut solution, &(), &mut eval, ()).unwrap();
+        assert_eq!((*solution, vns.neighbourhood()), (6, 0));
+    }
+}
@@ -0,0 +1,453 @@
+use core::error::Error;
+
+use std::{
+    time::{Duration, Instant},
+    vec::Vec,
+};
+
+use crate::{
+    Optimize,
+    eval::{Counter, Delta, Direction, Eval, Fork},
+    observe::Event,
+    op::Reset,
+    solution::{Move, Solution},
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum StopReason {
+    // NOTE: `path` lists the indices of the conditions that made the loop stop, from the outermost combinator inwards (see
+    //       `Event::StoppedBy`). For example, `or(a, or(b, c))` gives `[1, 1]` if `c` made it stop, and `[0]` if `a` did.
+    //       It is empty if the stop condition does not combine other conditions (or does not say which one was met).
+    Condition { path: Vec<usize> },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RunReport<O> {
+    // NOTE: Only the iterations of the outermost loop are counted, and not those of loops nested inside its operator.
+    pub iterations: usize,
+    // NOTE: This counts every objective value the solver asked for, including those a caching evaluator could answer
+    //       without evaluating the solution again (see `Counted` to count only the latter).
+    pub evaluations: usize,
+    // NOTE: This includes the time taken to create the initial solution.
+    pub elapsed: Duration,
+    // NOTE: This contains the best objective value found before every iteration of the outermost loop, and once more after
+    //       the last one, so the first entry is usually the objective value of the initial solution. It is built from
+    //       `Event::NewBest`, so it stays empty unless something reports new best solutions (such as `Stop::track` and
+    //       `RepeatUntil::keep_best`, or solvers that keep track of the best solution themselves).
+    pub trajectory: Vec<O>,
+    // NOTE: This is the index of the first entry in `trajectory` with the final best objective value, i.e. the number of
+    //       iterations it took to find the best solution.
+    pub best_iteration: Option<usize>,
+    // NOTE: This is `None` if the solver does not loop, or if it ended because an operator returned an error.
+    pub stop_reason: Option<StopReason>,
+}
+
+impl<O> RunReport<O> {
+    pub fn best_objective(&self) -> Option<&O> {
+        self.trajectory.last()
+    }
+}
+
+impl<O> Default for RunReport<O> {
+    fn default() -> Self {
+        Self {
+            iterations: 0,
+            evaluations: 0,
+            elapsed: Duration::ZERO,
+            trajectory: Vec::new(),
+            best_iteration: None,
+            stop_reason: None,
+        }
+    }
+}
+
+// NOTE: This wraps a solver so that its runs are reported. The solver is given the evaluator wrapped in `Reporting`, which
+//       counts evaluations and fills in a `RunReport` from the events reported by `RepeatUntil`, `Track` and the stop
+//       conditions (see `Event`). The report of the last run is kept until the next one starts, even if it failed.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[must_use]
+pub struct Report<T, O> {
+    op: T,
+    report: Option<RunReport<O>>,
+}
+
+impl<T, O> Report<T, O> {
+    pub fn new(op: T) -> Self {
+        Self { op, report: None }
+    }
+
+    pub fn report(&self) -> Option<&RunReport<O>> {
+        self.report.as_ref()
+    }
+
+    pub fn take_report(&mut self) -> Option<RunReport<O>> {
+        self.report.take()
+    }
+
+    pub fn optimize_with_report<P, S, E, Er>(
+        &mut self,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<(S, RunReport<O>), Er>
+    where
+        T: for<'a> Optimize<P, S, Reporting<&'a mut E, O>, Error = Er>,
+        S: Solution,
+        E: Eval<P, S::Individual, Objective = O>,
+        O: Clone,
+        Er: Error,
+    {
+        let solution = self.optimize(problem, eval)?;
+        let report = self.report.take().unwrap(); // PANICS: `optimize` always leaves a report behind.
+        Ok((solution, report))
+    }
+}
+
+impl<T, O> AsRef<T> for Report<T, O> {
+    fn as_ref(&self) -> &T {
+        &self.op
+    }
+}
+
+impl<T, O> AsMut<T> for Report<T, O> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.op
+    }
+}
+
+impl<T, P, S, E, Er> Optimize<P, S, E> for Report<T, E::Objective>
+where
+    T: for<'a> Optimize<P, S, Reporting<&'a mut E, E::Objective>, Error = Er>,
+    S: Solution,
+    E: Eval<P, S::Individual, Objective: Clone>,
+    Er: Error,
+{
+    type Error = Er;
+
+    fn optimize(&mut self, problem: &P, eval: &mut E) -> Result<S, Self::Error> {
+        let start = Instant::now();
+        let mut reporting = Reporting::new(eval);
+        let solution = self.op.optimize(problem, &mut reporting);
+        let mut report = reporting.into_report();
+        report.elapsed = start.elapsed();
+        self.report = Some(report);
+        solution
+    }
+}
+
+impl<T, O> Reset for Report<T, O>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+        self.report = None;
+    }
+}
+
+// NOTE: This is the evaluator `Report` gives to the solver it wraps. Loops nested inside the iterations of the outermost
+//       loop are told apart by keeping track of how deeply nested the current iteration is.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[must_use]
+pub struct Reporting<T, O> {
+    eval: T,
+    report: RunReport<O>,
+    best: Option<O>,
+    depth: usize,
+    // NOTE: The indices reported by `Event::StoppedBy` so far, from the innermost combinator outwards.
+    causes: Vec<usize>,
+}
+
+impl<T, O> Reporting<T, O> {
+    fn new(eval: T) -> Self {
+        Self {
+            eval,
+            report: RunReport::default(),
+            best: None,
+            depth: 0,
+            causes: Vec::new(),
+        }
+    }
+
+    fn into_report(mut self) -> RunReport<O>
+    where
+        O: PartialEq,
+    {
+        self.report.best_iteration = self.report.best_objective().and_then(|best| {
+            self.report
+                .trajectory
+                .iter()
+                .position(|objective| objective == best)
+        });
+        self.report
+    }
+
+    fn record(&mut self, event: Event<'_, O>)
+    where
+        O: Clone,
+    {
+        match event {
+            Event::IterationStarted { .. } => {
+                if self.depth == 0 {
+                    self.report.trajectory.extend(self.best.clone());
+                }
+                self.depth += 1;
+            },
+            Event::IterationFinished { .. } => {
+                self.depth = self.depth.saturating_sub(1);
+                if self.depth == 0 {
+                    self.report.iterations += 1;
+                }
+            },
+            Event::NewBest { objective } => self.best = Some(objective.clone()),
+            Event::StoppedBy { index } if self.depth == 0 => self.causes.push(index),
+            Event::Stopped { .. } if self.depth == 0 => {
+                self.report.trajectory.extend(self.best.clone());
+                let mut path = core::mem::take(&mut self.causes);
+                path.reverse();
+                self.report.stop_reason = Some(StopReason::Condition { path });
+            },
+            _ => {},
+        }
+    }
+}
+
+impl<T, O> AsRef<T> for Reporting<T, O> {
+    fn as_ref(&self) -> &T {
+        &self.eval
+    }
+}
+
+impl<T, O> AsMut<T> for Reporting<T, O> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.eval
+    }
+}
+
+impl<T, P, S> Eval<P, S> for Reporting<T, T::Objective>
+where
+    T: Eval<P, S, Objective: Clone>,
+{
+    type Objective = T::Objective;
+
+    fn eval(&mut self, solution: &S, problem: &P) -> Self::Objective {
+        if !self.eval.is_cached(solution, problem) {
+            self.report.evaluations += 1;
+        }
+        self.eval.eval(solution, problem)
+    }
+
+    fn direction(&self) -> Direction {
+        self.eval.direction()
+    }
+
+    fn cache_hit(&mut self) {
+        self.eval.cache_hit();
+    }
+
+    fn is_cached(&self, solution: &S, problem: &P) -> bool {
+        self.eval.is_cached(solution, problem)
+    }
+
+    fn observe(&mut self, event: Event<'_, Self::Objective>) {
+        self.eval.observe(event);
+        self.record(event);
+    }
+}
+
+impl<T, P, S, M> Delta<P, S, M> for Reporting<T, T::Objective>
+where
+    T: Delta<P, S, M, Objective: Clone>,
+    M: Move<S>,
+{
+    fn eval_move(
+        &mut self,
+        solution: &mut S,
+        problem: &P,
+        objective: &Self::Objective,
+        mv: &M,
+    ) -> Self::Objective {
+        self.report.evaluations += 1;
+        self.eval.eval_move(solution, problem, objective, mv)
+    }
+
+    fn apply_move(&mut self, solution: &mut S, problem: &P, mv: &M) {
+        self.eval.apply_move(solution, problem, mv);
+    }
+}
+
+impl<T, O> Counter for Reporting<T, O>
+where
+    T: Counter,
+{
+    fn evaluations(&self) -> usize {
+        self.eval.evaluations()
+    }
+
+    fn cache_hits(&self) -> usize {
+        self.eval.cache_hits()
+    }
+}
+
+// NOTE: Only the evaluations are merged back, since forked evaluators are only used to evaluate solutions.
+impl<T, O> Fork for Reporting<T, O>
+where
+    T: Fork,
+{
+    fn fork(&self) -> Self {
+        Self::new(self.eval.fork())
+    }
+
+    fn join(&mut self, other: Self) {
+        self.report.evaluations += other.report.evaluations;
+        self.eval.join(other.eval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{convert::Infallible, fmt};
+    use std::thread;
+
+    use crate::{
+        eval::{self, Counted, FromFn},
+        op::{
+            Operator,
+            RepeatUntil,
+            Then,
+            init::{self, FromIndividual},
+            stop::{Iterations, Optimum, Stop, any, or},
+        },
+        solution::Individual,
+    };
+
+    use super::*;
+
+    struct Climb;
+
+    impl<P, E> Operator<P, Individual<i32>, E> for Climb
+    where
+        E: Eval<P, i32>,
+    {
+        type Output = ();
+
+        type Error = Infallible;
+
+        fn apply(
+            &mut self,
+            solution: &mut Individual<i32>,
+            _problem: &P,
+            _eval: &mut E,
+            _input: (),
+        ) -> Result<Self::Output, Self::Error> {
+            **solution += 1;
+            Ok(())
+        }
+    }
+
+    impl Reset for Climb {
+        fn reset(&mut self) {}
+    }
+
+    type Identity = FromFn<fn(&i32, &()) -> i32>;
+
+    fn identity() -> Counted<Identity> {
+        let identity: fn(&i32, &()) -> i32 = |solution, _problem| *solution;
+        eval::from_fn(identity).counted()
+    }
+
+    fn climb<F>(cond: F) -> Report<Then<FromIndividual<i32>, RepeatUntil<Climb, F>>, i32>
+    where
+        F: Stop<(), Individual<i32>, Identity>,
+    {
+        let repeat = Operator::<(), Individual<i32>, Identity>::repeat_until(Climb, cond);
+        let init = init::from_individual(0);
+        Report::new(Operator::<(), Individual<i32>, Identity>::then(
+            init, repeat,
+        ))
+    }
+
+    #[test]
+    fn reports_iterations_evaluations_and_trajectory() {
+        let mut eval = identity();
+        let mut solver = climb(Stop::<(), Individual<i32>, Identity>::track(or(
+            Optimum(3),
+            Iterations::new(10),
+        )));
+        let (solution, report) = solver.optimize_with_report(&(), &mut eval).unwrap();
+        assert_eq!(*solution, 3);
+        assert_eq!(report.iterations, 3);
+        assert_eq!(report.evaluations, eval.evaluations());
+        assert_eq!(report.trajectory, [0, 1, 2, 3]);
+        assert_eq!(report.best_objective(), Some(&3));
+        assert_eq!(report.best_iteration, Some(3));
+        assert_eq!(
+            report.stop_reason,
+            Some(StopReason::Condition { path: [0].into() })
+        );
+        assert!(solver.report().is_none());
+    }
+
+    #[test]
+    fn reports_which_nested_condition_stopped() {
+        let mut eval = identity();
+        let mut solver = climb(any((
+            Iterations::new(10),
+            or(Optimum(100), Iterations::new(2)),
+        )));
+        let (solution, report) = solver.optimize_with_report(&(), &mut eval).unwrap();
+        assert_eq!(*solution, 2);
+        assert_eq!(report.iterations, 2);
+        assert_eq!(
+            report.stop_reason,
+            Some(StopReason::Condition {
+                path: [1, 1].into()
+            })
+        );
+
+        // NOTE: Nothing reports new best solutions here, and the stop condition does not combine other conditions.
+        assert!(report.trajectory.is_empty());
+        assert_eq!(report.best_iteration, None);
+
+        let mut solver = climb(Iterations::new(4));
+        let (_, report) = solver.optimize_with_report(&(), &mut eval).unwrap();
+        assert_eq!(report.iterations, 4);
+        assert_eq!(
+            report.stop_reason,
+            Some(StopReason::Condition { path: [].into() })
+        );
+    }
+
+    // NOTE: This takes a while to create a solution, and then fails in the middle of its first iteration.
+    struct Fail;
+
+    impl<P, E> Optimize<P, Individual<i32>, E> for Fail
+    where
+        E: Eval<P, i32>,
+    {
+        type Error = fmt::Error;
+
+        fn optimize(&mut self, problem: &P, eval: &mut E) -> Result<Individual<i32>, Self::Error> {
+            thread::sleep(Duration::from_millis(5));
+            let _ = eval.eval(&0, problem);
+            eval.observe(Event::IterationStarted { iteration: 0 });
+            Err(fmt::Error)
+        }
+    }
+
+    #[test]
+    fn keeps_report_of_failed_run_including_init() {
+        let mut eval = identity();
+        let mut solver = Report::new(Fail);
+        assert_eq!(
+            Optimize::<(), Individual<i32>, _>::optimize(&mut solver, &(), &mut eval),
+            Err(fmt::Error)
+        );
+
+        let report = solver.report().unwrap();
+        assert!(report.elapsed >= Duration::from_millis(5));
+        assert_eq!(report.iterations, 0);
+        assert_eq!(report.evaluations, 1);
+        assert_eq!(report.stop_reason, None);
+    }
+}
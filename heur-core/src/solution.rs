@@ -9,6 +9,11 @@ use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
 mod evaluated;
 pub use evaluated::Evaluated;
 
+#[cfg(feature = "std")]
+mod sync_evaluated;
+#[cfg(feature = "std")]
+pub use sync_evaluated::SyncEvaluated;
+
 // TODO: 1. Impl `Solution` for types from `smallvec`, `arrayvec`, `tinyvec`, `heapless`, and/or `im`
 //       2. Add `#[diagnostic::on_unimplemented]`
 pub trait Solution {
@@ -16,6 +21,8 @@ pub trait Solution {
 }
 
 #[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[repr(transparent)]
 pub struct Individual<T>(pub T);
 
@@ -156,6 +163,34 @@ impl<T> Solution for VecDeque<T> {
 #[cfg(feature = "alloc")]
 impl<T> Population for VecDeque<T> {}
 
+// NOTE: Moves describe small changes to a solution (such as flipping a single bit) that can be applied and undone in place.
+//       This lets evaluators compute the objective value of a neighbouring solution incrementally (see `eval::Delta`),
+//       and lets operators explore neighbouring solutions without having to clone the entire solution.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait Move<S>
+where
+    S: ?Sized,
+{
+    fn apply(&self, solution: &mut S);
+
+    // NOTE: This must restore the solution to exactly the state it was in before `apply` was called.
+    fn undo(&self, solution: &mut S);
+}
+
+impl<M, S> Move<S> for &M
+where
+    M: Move<S> + ?Sized,
+    S: ?Sized,
+{
+    fn apply(&self, solution: &mut S) {
+        M::apply(self, solution)
+    }
+
+    fn undo(&self, solution: &mut S) {
+        M::undo(self, solution)
+    }
+}
+
 // NOTE: We need these traits due to a possible bug in `rustc` where trying to prove that `Evaluated<S, O>` impls
 //       `IntoIterator` puts the trait solver into a loop and leads to an overflow. Conceptually, `T: for<'a> Iter<'a>`
 //       is exactly the same as `for<'a> &'a T: IntoIterator<Item = &'a U>`, but the latter leads to E0275 ("overflow
@@ -4,6 +4,15 @@ use core::{
     ops::{Deref, DerefMut},
 };
 
+// NOTE: `serde` only supports `Cell<T>` where `T: Copy`, hence the manually specified bounds.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, O: serde::Serialize + Copy",
+        deserialize = "S: serde::Deserialize<'de>, O: serde::Deserialize<'de> + Copy",
+    ))
+)]
 #[must_use]
 pub struct Evaluated<S, O> {
     solution: S,
@@ -33,6 +42,19 @@ impl<S, O> Evaluated<S, O> {
         self.objective.get()
     }
 
+    // NOTE: These allow evaluators in this crate to modify the solution without invalidating the cached objective value,
+    //       which they must then keep up to date themselves.
+    pub(crate) fn solution_mut(&mut self) -> &mut S {
+        &mut self.solution
+    }
+
+    pub(crate) fn set_objective(&self, objective: Option<O>)
+    where
+        O: Copy,
+    {
+        self.objective.set(objective);
+    }
+
     pub(crate) fn objective_or_eval<F>(&self, eval: F) -> O
     where
         F: FnOnce(&S) -> O,
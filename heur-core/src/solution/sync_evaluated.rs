@@ -0,0 +1,190 @@
+use core::ops::{Deref, DerefMut};
+
+use std::sync::OnceLock;
+
+// NOTE: Unlike `Evaluated<S, O>`, this uses a `OnceLock<O>` to store the objective value, so it is `Sync` (as long as
+//       `S` and `O` are) and can be used with objective values that are only `Clone`. This comes at the cost of requiring
+//       `std` and some synchronisation overhead, so `Evaluated<S, O>` should be preferred when neither is needed.
+//
+//       Also unlike `Evaluated<S, O>`, only the solutions are compared, so that a copy whose objective value has already
+//       been evaluated by another thread is still equal to one that hasn't.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct SyncEvaluated<S, O> {
+    solution: S,
+    // NOTE: The cached objective value is not (de)serialized, since it can always be re-evaluated.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    objective: OnceLock<O>,
+}
+
+impl<S, O> SyncEvaluated<S, O> {
+    pub fn new(solution: S) -> Self {
+        Self {
+            solution,
+            objective: OnceLock::new(),
+        }
+    }
+
+    pub fn into_inner(this: Self) -> S {
+        this.solution
+    }
+
+    pub fn objective(&self) -> Option<&O> {
+        self.objective.get()
+    }
+
+    // NOTE: See the note on the corresponding functions for `Evaluated<S, O>`.
+    pub(crate) fn solution_mut(&mut self) -> &mut S {
+        &mut self.solution
+    }
+
+    pub(crate) fn set_objective(&mut self, objective: Option<O>) {
+        self.objective = objective.map(OnceLock::from).unwrap_or_default();
+    }
+
+    pub(crate) fn objective_or_eval<F>(&self, eval: F) -> &O
+    where
+        F: FnOnce(&S) -> O,
+    {
+        self.objective.get_or_init(|| eval(&self.solution))
+    }
+}
+
+impl<S, O> Eq for SyncEvaluated<S, O> where S: Eq {}
+
+impl<S, O> PartialEq for SyncEvaluated<S, O>
+where
+    S: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.solution == other.solution
+    }
+}
+
+impl<S, O> Deref for SyncEvaluated<S, O> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        &self.solution
+    }
+}
+
+impl<S, O> DerefMut for SyncEvaluated<S, O> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.objective.take();
+        &mut self.solution
+    }
+}
+
+impl<S, O> AsRef<S> for SyncEvaluated<S, O> {
+    fn as_ref(&self) -> &S {
+        self
+    }
+}
+
+impl<S, O> AsMut<S> for SyncEvaluated<S, O> {
+    fn as_mut(&mut self) -> &mut S {
+        self
+    }
+}
+
+impl<S, O> IntoIterator for SyncEvaluated<S, O>
+where
+    S: IntoIterator,
+{
+    type Item = S::Item;
+
+    type IntoIter = S::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Self::into_inner(self).into_iter()
+    }
+}
+
+impl<'a, S, O> IntoIterator for &'a SyncEvaluated<S, O>
+where
+    &'a S: IntoIterator,
+{
+    type Item = <&'a S as IntoIterator>::Item;
+
+    type IntoIter = <&'a S as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // NOTE: See the note in the corresponding impl for `&Evaluated<S, O>`.
+        let solution = &**self;
+        solution.into_iter()
+    }
+}
+
+impl<'a, S, O> IntoIterator for &'a mut SyncEvaluated<S, O>
+where
+    &'a mut S: IntoIterator,
+{
+    type Item = <&'a mut S as IntoIterator>::Item;
+
+    type IntoIter = <&'a mut S as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // NOTE: We need to deref here so that the cached objective value can be reset.
+        let solution = &mut **self;
+        solution.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn objective_is_evaluated_once_across_threads() {
+        let solution = SyncEvaluated::new(21);
+        let evaluations = AtomicUsize::new(0);
+
+        let objectives: Vec<i32> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|_| {
+                    scope.spawn(|| {
+                        *solution.objective_or_eval(|solution| {
+                            evaluations.fetch_add(1, Ordering::Relaxed);
+                            solution * 2
+                        })
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+
+        assert!(objectives.iter().all(|&objective| objective == 42));
+        assert_eq!(evaluations.load(Ordering::Relaxed), 1);
+        assert_eq!(solution.objective(), Some(&42));
+    }
+
+    #[test]
+    fn mutating_clears_cached_objective() {
+        let mut solution = SyncEvaluated::new(21);
+        let _ = solution.objective_or_eval(|solution| solution * 2);
+        assert_eq!(solution.objective(), Some(&42));
+
+        *solution += 1;
+        assert_eq!(solution.objective(), None);
+        assert_eq!(*solution.objective_or_eval(|solution| solution * 2), 44);
+    }
+
+    #[test]
+    fn equality_ignores_cached_objective() {
+        let evaluated = SyncEvaluated::new(21);
+        let _ = evaluated.objective_or_eval(|solution| solution * 2);
+
+        assert_eq!(evaluated, SyncEvaluated::new(21));
+        assert_ne!(evaluated, SyncEvaluated::new(22));
+    }
+}
@@ -1,16 +1,21 @@
-use core::marker::PhantomData;
+use core::{error::Error, marker::PhantomData};
 
 use alloc::{boxed::Box, vec::Vec};
 
+use rand::{Rng, distr::Distribution};
+
 use heur_core::{
     eval::Eval,
-    op::{Hint, Operator, Unwrapped},
+    op::{Choose, ErrInto, Hint, Operator, Unwrapped},
     solution::Population,
 };
 
 mod on_combined;
 pub use on_combined::OnCombined;
 
+mod list;
+pub use list::CombineList;
+
 mod uniform;
 pub use uniform::{UniformCrossover, UniformCrossoverError};
 
@@ -126,6 +131,46 @@ where
     }
 }
 
+impl<T, Err, P, S, E> Combine<P, S, E> for ErrInto<T, Err>
+where
+    T: Combine<P, S, E, Error: Into<Err>>,
+    Err: Error,
+    S: Population,
+    E: Eval<P, S::Individual>,
+{
+    fn combine(
+        &mut self,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+        selected: Vec<S::Individual>,
+    ) -> Result<Vec<S::Individual>, Self::Error> {
+        self.as_mut()
+            .combine(population, problem, eval, selected)
+            .map_err(Into::into)
+    }
+}
+
+impl<L, D, R, P, S, E> Combine<P, S, E> for Choose<L, D, R>
+where
+    L: CombineList<P, S, E>,
+    D: Distribution<usize>,
+    R: Rng,
+    S: Population,
+    E: Eval<P, S::Individual>,
+{
+    fn combine(
+        &mut self,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+        selected: Vec<S::Individual>,
+    ) -> Result<Vec<S::Individual>, Self::Error> {
+        let (idx, ops) = self.choose();
+        ops.combine_nth(idx, population, problem, eval, selected)
+    }
+}
+
 pub fn on_combined<P, S, E, T>(op: T) -> OnCombined<T, P, S, E>
 where
     T: Operator<P, Vec<S::Individual>, E, Output = ()>,
@@ -0,0 +1,163 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use heur_core::{eval::Eval, op::OperatorList, solution::Population};
+
+use super::Combine;
+
+// NOTE: Like `OperatorList`, but for lists of `Combine` operators. This lets `Choose` pick between several crossover
+//       operators.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait CombineList<P, S, E>:
+    OperatorList<P, S, E, Vec<S::Individual>, Output = Vec<S::Individual>>
+where
+    S: Population,
+    E: Eval<P, S::Individual>,
+{
+    // NOTE: This panics if `index` is out of bounds.
+    fn combine_nth(
+        &mut self,
+        index: usize,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+        selected: Vec<S::Individual>,
+    ) -> Result<Vec<S::Individual>, Self::Error>;
+}
+
+impl<T, P, S, E> CombineList<P, S, E> for &mut T
+where
+    T: CombineList<P, S, E> + ?Sized,
+    S: Population,
+    E: Eval<P, S::Individual>,
+{
+    fn combine_nth(
+        &mut self,
+        index: usize,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+        selected: Vec<S::Individual>,
+    ) -> Result<Vec<S::Individual>, Self::Error> {
+        T::combine_nth(self, index, population, problem, eval, selected)
+    }
+}
+
+impl<T, P, S, E> CombineList<P, S, E> for Box<T>
+where
+    T: CombineList<P, S, E> + ?Sized,
+    S: Population,
+    E: Eval<P, S::Individual>,
+{
+    fn combine_nth(
+        &mut self,
+        index: usize,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+        selected: Vec<S::Individual>,
+    ) -> Result<Vec<S::Individual>, Self::Error> {
+        T::combine_nth(self, index, population, problem, eval, selected)
+    }
+}
+
+impl<T, P, S, E> CombineList<P, S, E> for [T]
+where
+    T: Combine<P, S, E>,
+    S: Population,
+    E: Eval<P, S::Individual>,
+{
+    fn combine_nth(
+        &mut self,
+        index: usize,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+        selected: Vec<S::Individual>,
+    ) -> Result<Vec<S::Individual>, Self::Error> {
+        self[index].combine(population, problem, eval, selected)
+    }
+}
+
+impl<T, P, S, E, const N: usize> CombineList<P, S, E> for [T; N]
+where
+    T: Combine<P, S, E>,
+    S: Population,
+    E: Eval<P, S::Individual>,
+{
+    fn combine_nth(
+        &mut self,
+        index: usize,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+        selected: Vec<S::Individual>,
+    ) -> Result<Vec<S::Individual>, Self::Error> {
+        self[index].combine(population, problem, eval, selected)
+    }
+}
+
+impl<T, P, S, E> CombineList<P, S, E> for Vec<T>
+where
+    T: Combine<P, S, E>,
+    S: Population,
+    E: Eval<P, S::Individual>,
+{
+    fn combine_nth(
+        &mut self,
+        index: usize,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+        selected: Vec<S::Individual>,
+    ) -> Result<Vec<S::Individual>, Self::Error> {
+        self[index].combine(population, problem, eval, selected)
+    }
+}
+
+macro_rules! impl_for_tuples {
+    ($(($first:ident $(, $rest:ident)*)),+ $(,)?) => {
+        $(
+            impl<$first, $($rest,)* P, S, E> CombineList<P, S, E> for ($first, $($rest,)*)
+            where
+                $first: Combine<P, S, E>,
+                $($rest: Combine<P, S, E, Error = $first::Error>,)*
+                S: Population,
+                E: Eval<P, S::Individual>,
+            {
+                #[allow(non_snake_case)]
+                fn combine_nth(
+                    &mut self,
+                    index: usize,
+                    population: &S,
+                    problem: &P,
+                    eval: &mut E,
+                    selected: Vec<S::Individual>,
+                ) -> Result<Vec<S::Individual>, Self::Error> {
+                    let len = self.len();
+                    let ($first, $($rest,)*) = self;
+                    let mut indices = 0..;
+                    if indices.next() == Some(index) {
+                        return $first.combine(population, problem, eval, selected);
+                    }
+                    $(
+                        if indices.next() == Some(index) {
+                            return $rest.combine(population, problem, eval, selected);
+                        }
+                    )*
+                    panic!("index out of bounds: the len is {len} but the index is {index}")
+                }
+            }
+        )+
+    };
+}
+
+impl_for_tuples!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, F),
+    (A, B, C, D, F, G),
+    (A, B, C, D, F, G, H),
+    (A, B, C, D, F, G, H, I),
+);
@@ -6,7 +6,11 @@ use core::{
 
 use alloc::vec::Vec;
 
-use heur_core::{eval::Eval, op::Operator, solution::Population};
+use heur_core::{
+    eval::Eval,
+    op::{Operator, Reset},
+    solution::Population,
+};
 
 #[must_use]
 pub struct OnCombined<T, P, S, E> {
@@ -86,3 +90,12 @@ where
         self.marker.hash(state);
     }
 }
+
+impl<T, P, S, E> Reset for OnCombined<T, P, S, E>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.op.reset();
+    }
+}
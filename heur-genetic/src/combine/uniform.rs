@@ -1,4 +1,5 @@
 use core::{
+    convert::Infallible,
     error::Error,
     fmt::{self, Display, Formatter},
     iter,
@@ -14,13 +15,14 @@ use rand::{
 
 use heur_core::{
     eval::Eval,
-    op::Operator,
+    op::{Operator, Reset},
     solution::{IterMut, Population},
 };
 
 use super::Combine;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct UniformCrossover<R> {
     dist: Bernoulli,
@@ -110,3 +112,14 @@ impl Display for UniformCrossoverError {
 }
 
 impl Error for UniformCrossoverError {}
+
+// NOTE: This lets operators that cannot fail be combined with this one (see `Operator::err_into`).
+impl From<Infallible> for UniformCrossoverError {
+    fn from(error: Infallible) -> Self {
+        match error {}
+    }
+}
+
+impl<R> Reset for UniformCrossover<R> {
+    fn reset(&mut self) {}
+}
@@ -1,8 +1,10 @@
+use core::error::Error;
+
 use alloc::{boxed::Box, vec::Vec};
 
 use heur_core::{
     eval::Eval,
-    op::{Hint, Ignore, Operator, Unwrapped},
+    op::{ErrInto, Hint, Ignore, Operator, Unwrapped},
     solution::Population,
 };
 
@@ -133,3 +135,23 @@ where
         self.as_mut().insert(population, problem, eval, combined)
     }
 }
+
+impl<T, Err, P, S, E> Insert<P, S, E> for ErrInto<T, Err>
+where
+    T: Insert<P, S, E, Error: Into<Err>>,
+    Err: Error,
+    S: Population,
+    E: Eval<P, S::Individual>,
+{
+    fn insert(
+        &mut self,
+        population: &mut S,
+        problem: &P,
+        eval: &mut E,
+        combined: Vec<S::Individual>,
+    ) -> Result<(), Self::Error> {
+        self.as_mut()
+            .insert(population, problem, eval, combined)
+            .map_err(Into::into)
+    }
+}
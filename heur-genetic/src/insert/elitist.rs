@@ -2,15 +2,27 @@ use core::convert::Infallible;
 
 use alloc::vec::Vec;
 
-use heur_core::{eval::Eval, op::Operator, solution::Population};
+use heur_core::{
+    eval::Eval,
+    op::{Operator, Reset},
+    solution::Population,
+};
+
+#[cfg(feature = "rayon")]
+use heur_core::eval::{Fork, par_eval};
+
+#[cfg(feature = "rayon")]
+use crate::Parallel;
 
 use super::Insert;
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct ElitistInserter {
     // NOTE: We store the indices as part of the struct itself to avoid re-allocating a new vec for them every time we
     //       need to insert individuals.
+    #[cfg_attr(feature = "serde", serde(skip))]
     indices: Vec<usize>,
 }
 
@@ -20,6 +32,19 @@ impl ElitistInserter {
             indices: Vec::new(),
         }
     }
+
+    #[cfg(feature = "rayon")]
+    pub fn parallel(self) -> Parallel<Self> {
+        Parallel(self)
+    }
+
+    // NOTE: This expects `self.indices` to already be sorted with the worst individuals at the front.
+    fn insert_sorted<I>(&mut self, population: &mut [I], combined: Vec<I>) {
+        for (idx, offspring) in self.indices.drain(..).zip(combined) {
+            let parent = &mut population[idx];
+            *parent = offspring;
+        }
+    }
 }
 
 impl<P, S, E> Operator<P, S, E, Vec<S::Individual>> for ElitistInserter
@@ -56,18 +81,137 @@ where
     ) -> Result<(), Self::Error> {
         let population = population.as_mut();
 
-        // Create a list of indices to individuals in the solution sorted by their objective values
+        // Create a list of indices to individuals in the solution sorted by their objective values, worst first
+        let direction = eval.direction();
         self.indices.extend(0..population.len());
         self.indices.sort_by_cached_key(|&idx| {
             let solution = &population[idx];
-            eval.eval(solution, problem)
+            direction.rank(eval.eval(solution, problem))
         });
 
-        for (idx, offspring) in self.indices.drain(..).zip(combined) {
-            let parent = &mut population[idx];
-            *parent = offspring;
-        }
+        self.insert_sorted(population, combined);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<P, S, E> Operator<P, S, E, Vec<S::Individual>> for Parallel<ElitistInserter>
+where
+    S: Population<Individual: Clone + Sync> + AsMut<[S::Individual]>,
+    P: Sync,
+    E: Eval<P, S::Individual, Objective: Ord + Send> + Fork + Send + Sync,
+{
+    type Output = ();
+
+    type Error = Infallible;
+
+    fn apply(
+        &mut self,
+        population: &mut S,
+        problem: &P,
+        eval: &mut E,
+        combined: Vec<S::Individual>,
+    ) -> Result<Self::Output, Self::Error> {
+        self.insert(population, problem, eval, combined)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<P, S, E> Insert<P, S, E> for Parallel<ElitistInserter>
+where
+    S: Population<Individual: Clone + Sync> + AsMut<[S::Individual]>,
+    P: Sync,
+    E: Eval<P, S::Individual, Objective: Ord + Send> + Fork + Send + Sync,
+{
+    fn insert(
+        &mut self,
+        population: &mut S,
+        problem: &P,
+        eval: &mut E,
+        combined: Vec<S::Individual>,
+    ) -> Result<(), Self::Error> {
+        let population = population.as_mut();
+
+        // Evaluate every individual in parallel, then sort the indices by their objective values, worst first
+        let direction = eval.direction();
+        let objectives = par_eval(population, problem, eval);
+        self.0.indices.extend(0..population.len());
+        self.0
+            .indices
+            .sort_by_key(|&idx| direction.rank(&objectives[idx]));
+
+        self.0.insert_sorted(population, combined);
 
         Ok(())
     }
 }
+
+// NOTE: See the note on the `Reset` impl for `ElitistSelector`.
+impl Reset for ElitistInserter {
+    fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use heur_core::eval::{self, Eval};
+
+    use super::*;
+
+    #[test]
+    fn replaces_worst_when_maximizing() {
+        let mut population = vec![3, 1, 4, 2];
+        let mut eval = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+        let mut inserter = ElitistInserter::new();
+        inserter
+            .insert(&mut population, &(), &mut eval, vec![10, 20])
+            .unwrap();
+        assert_eq!(population, vec![3, 10, 4, 20]);
+    }
+
+    #[test]
+    fn replaces_worst_when_minimizing() {
+        let mut population = vec![3, 1, 4, 2];
+        let mut eval = eval::from_fn(|solution: &i32, _: &()| *solution).minimize();
+        let mut inserter = ElitistInserter::new();
+        inserter
+            .insert(&mut population, &(), &mut eval, vec![10, 20])
+            .unwrap();
+        assert_eq!(population, vec![20, 1, 10, 2]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_matches_sequential() {
+        let population = (0..20)
+            .map(|solution| solution * 7 % 20)
+            .collect::<Vec<i32>>();
+        let combined = vec![5, 30, -5];
+
+        let mut max = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+        let mut sequential = population.clone();
+        ElitistInserter::new()
+            .insert(&mut sequential, &(), &mut max, combined.clone())
+            .unwrap();
+        let mut parallel = population.clone();
+        ElitistInserter::new()
+            .parallel()
+            .insert(&mut parallel, &(), &mut max, combined.clone())
+            .unwrap();
+        assert_eq!(parallel, sequential);
+
+        let mut min = eval::from_fn(|solution: &i32, _: &()| *solution).minimize();
+        let mut sequential = population.clone();
+        ElitistInserter::new()
+            .insert(&mut sequential, &(), &mut min, combined.clone())
+            .unwrap();
+        let mut parallel = population;
+        ElitistInserter::new()
+            .parallel()
+            .insert(&mut parallel, &(), &mut min, combined)
+            .unwrap();
+        assert_eq!(parallel, sequential);
+    }
+}
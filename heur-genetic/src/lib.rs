@@ -13,7 +13,7 @@ use combine::{Combine, on_combined};
 use heur_core::{
     Optimize,
     eval::Eval,
-    op::{Operator, init::Init, stop::Stop},
+    op::{Operator, Reset, init::Init, stop::Stop},
     solution::Population,
 };
 
@@ -27,7 +27,13 @@ pub mod combine;
 
 pub mod insert;
 
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use parallel::Parallel;
+
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GeneticAlgorithm<Ini, Sel, Com, Mut, Ins, Sto> {
     pub init: Ini,
     pub select: Sel,
@@ -42,22 +48,27 @@ impl<P, S, E, Ini, Sel, Com, Mut, Ins, Sto> Optimize<P, S, E>
 where
     S: Population,
     E: Eval<P, S::Individual>,
-    Ini: Init<P, S, E, Output = ()>,
-    Sel: Select<P, S, E, Error = Ini::Error>,
-    Com: Combine<P, S, E, Error = Ini::Error>,
-    Mut: Operator<P, Vec<S::Individual>, E, Output = (), Error = Ini::Error>,
-    Ins: Insert<P, S, E, Output = (), Error = Ini::Error>,
-    Sto: Stop<P, S, E>,
+    Ini: Init<P, S, E, Output = ()> + Reset,
+    Sel: Select<P, S, E, Error = Ini::Error> + Reset,
+    Com: Combine<P, S, E, Error = Ini::Error> + Reset,
+    Mut: Operator<P, Vec<S::Individual>, E, Output = (), Error = Ini::Error> + Reset,
+    Ins: Insert<P, S, E, Output = (), Error = Ini::Error> + Reset,
+    Sto: Stop<P, S, E> + Reset,
 {
     type Error = Ini::Error;
 
     fn optimize(&mut self, problem: &P, eval: &mut E) -> Result<S, Self::Error> {
+        // NOTE: This lets the same algorithm be run more than once (see `Reset`).
+        self.reset();
+
         let init = self.init.by_ref();
         let select = self.select.by_ref();
         let combine = self.combine.by_ref();
         let mutate = self.mutate.by_ref();
         let insert = self.insert.by_ref();
 
+        // NOTE: Observer events (see `heur_core::observe`) are emitted by the combinators below, so each generation is
+        //       reported as one iteration of `repeat_until`.
         let mut ga = init.then(
             select
                 .pipe(combine)
@@ -69,3 +80,22 @@ where
         ga.optimize(problem, eval)
     }
 }
+
+impl<Ini, Sel, Com, Mut, Ins, Sto> Reset for GeneticAlgorithm<Ini, Sel, Com, Mut, Ins, Sto>
+where
+    Ini: Reset,
+    Sel: Reset,
+    Com: Reset,
+    Mut: Reset,
+    Ins: Reset,
+    Sto: Reset,
+{
+    fn reset(&mut self) {
+        self.init.reset();
+        self.select.reset();
+        self.combine.reset();
+        self.mutate.reset();
+        self.insert.reset();
+        self.stop.reset();
+    }
+}
@@ -1,10 +1,10 @@
-use core::marker::PhantomData;
+use core::{error::Error, marker::PhantomData};
 
 use alloc::{boxed::Box, vec::Vec};
 
 use heur_core::{
     eval::Eval,
-    op::{Hint, Operator, Unwrapped},
+    op::{ErrInto, Hint, Operator, Unwrapped},
     solution::Population,
 };
 
@@ -185,6 +185,37 @@ where
     }
 }
 
+impl<T, Err, P, S, E> Select<P, S, E> for ErrInto<T, Err>
+where
+    T: Select<P, S, E, Error: Into<Err>>,
+    Err: Error,
+    S: Population,
+    E: Eval<P, S::Individual>,
+{
+    fn select(
+        &mut self,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<Vec<S::Individual>, Self::Error> {
+        self.as_mut()
+            .select(population, problem, eval)
+            .map_err(Into::into)
+    }
+
+    fn select_into(
+        &mut self,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+        selected: &mut Vec<S::Individual>,
+    ) -> Result<(), Self::Error> {
+        self.as_mut()
+            .select_into(population, problem, eval, selected)
+            .map_err(Into::into)
+    }
+}
+
 pub fn on_selected<P, S, E, T>(op: T) -> OnSelected<T, P, S, E>
 where
     T: Operator<P, Vec<S::Individual>, E, Output = ()>,
@@ -2,16 +2,28 @@ use core::{cmp::Reverse, convert::Infallible};
 
 use alloc::vec::Vec;
 
-use heur_core::{eval::Eval, op::Operator, solution::Population};
+use heur_core::{
+    eval::Eval,
+    op::{Operator, Reset},
+    solution::Population,
+};
+
+#[cfg(feature = "rayon")]
+use heur_core::eval::{Fork, par_eval};
+
+#[cfg(feature = "rayon")]
+use crate::Parallel;
 
 use super::Select;
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct ElitistSelector {
     selection_size: usize,
     // NOTE: We store the indices as part of the struct itself to avoid re-allocating a new vec for them every time we
     //       need to select individuals.
+    #[cfg_attr(feature = "serde", serde(skip))]
     indices: Vec<usize>,
 }
 
@@ -22,6 +34,33 @@ impl ElitistSelector {
             indices: Vec::new(),
         }
     }
+
+    #[cfg(feature = "rayon")]
+    pub fn parallel(self) -> Parallel<Self> {
+        Parallel(self)
+    }
+
+    // NOTE: This expects `self.indices` to already be sorted with the best individuals at the front.
+    fn select_sorted<I>(&mut self, population: &[I], selected: &mut Vec<I>)
+    where
+        I: Clone,
+    {
+        // Pick the `selection_size` best indivdiuals
+        // NOTE: We don't check whether `selection_size <= population.len()`, so if `selection_size` is greater than the
+        //       number of individuals available, we will invariably end up selecting repeated individuals, but this is fine.
+        selected.clear();
+        selected.extend(
+            self.indices
+                .iter()
+                .cycle()
+                .take(self.selection_size)
+                .map(|&idx| &population[idx])
+                .cloned(),
+        );
+
+        // Clear the indices so that the next time we select we have a blank state (but with a reusable allocation)
+        self.indices.clear();
+    }
 }
 
 impl<P, S, E> Operator<P, S, E> for ElitistSelector
@@ -70,30 +109,113 @@ where
         let population = population.as_ref();
 
         // Create a list of indices to individuals in the solution sorted by their objective values
+        let direction = eval.direction();
         self.indices.extend(0..population.len());
         self.indices.sort_by_cached_key(|&idx| {
             let solution = &population[idx];
 
-            // NOTE: We reverse the comparison order because we need the best (largest) objective values to be at the front.
-            Reverse(eval.eval(solution, problem))
+            // NOTE: We reverse the comparison order because we need the best objective values to be at the front.
+            Reverse(direction.rank(eval.eval(solution, problem)))
         });
 
-        // Pick the `selection_size` best indivdiuals
-        // NOTE: We don't check whether `selection_size <= population.len()`, so if `selection_size` is greater than the
-        //       number of individuals available, we will invariably end up selecting repeated individuals, but this is fine.
-        selected.clear();
-        selected.extend(
-            self.indices
-                .iter()
-                .cycle()
-                .take(self.selection_size)
-                .map(|&idx| &population[idx])
-                .cloned(),
-        );
+        self.select_sorted(population, selected);
 
-        // Clear the indices so that the next time we select we have a blank state (but with a reusable allocation)
-        self.indices.clear();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<P, S, E> Operator<P, S, E> for Parallel<ElitistSelector>
+where
+    S: Population<Individual: Clone + Sync> + AsRef<[S::Individual]>,
+    P: Sync,
+    E: Eval<P, S::Individual, Objective: Ord + Send> + Fork + Send + Sync,
+{
+    type Output = Vec<S::Individual>;
+
+    type Error = Infallible;
+
+    fn apply(
+        &mut self,
+        population: &mut S,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.select(population, problem, eval)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<P, S, E> Select<P, S, E> for Parallel<ElitistSelector>
+where
+    S: Population<Individual: Clone + Sync> + AsRef<[S::Individual]>,
+    P: Sync,
+    E: Eval<P, S::Individual, Objective: Ord + Send> + Fork + Send + Sync,
+{
+    fn select(
+        &mut self,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<Vec<S::Individual>, Self::Error> {
+        let mut selected = Vec::with_capacity(self.0.selection_size);
+        self.select_into(population, problem, eval, &mut selected)?;
+        Ok(selected)
+    }
+
+    fn select_into(
+        &mut self,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+        selected: &mut Vec<S::Individual>,
+    ) -> Result<(), Self::Error> {
+        let population = population.as_ref();
+
+        // Evaluate every individual in parallel, then sort the indices by their objective values, best first
+        let direction = eval.direction();
+        let objectives = par_eval(population, problem, eval);
+        self.0.indices.extend(0..population.len());
+        self.0
+            .indices
+            .sort_by_key(|&idx| Reverse(direction.rank(&objectives[idx])));
+
+        self.0.select_sorted(population, selected);
 
         Ok(())
     }
 }
+
+// NOTE: The buffer of indices is only used as scratch space, so there is nothing to reset.
+impl Reset for ElitistSelector {
+    fn reset(&mut self) {}
+}
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use heur_core::eval::{self, Eval};
+
+    use super::*;
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let population = (0..20)
+            .map(|solution| solution * 7 % 20)
+            .collect::<Vec<i32>>();
+
+        let mut max = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+        let mut sequential = ElitistSelector::new(5);
+        let mut parallel = ElitistSelector::new(5).parallel();
+        assert_eq!(
+            parallel.select(&population, &(), &mut max),
+            sequential.select(&population, &(), &mut max)
+        );
+
+        let mut min = eval::from_fn(|solution: &i32, _: &()| *solution).minimize();
+        assert_eq!(
+            parallel.select(&population, &(), &mut min),
+            sequential.select(&population, &(), &mut min)
+        );
+    }
+}
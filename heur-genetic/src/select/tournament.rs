@@ -1,4 +1,5 @@
 use core::{
+    convert::Infallible,
     error::Error,
     fmt::{self, Display, Formatter},
 };
@@ -7,11 +8,25 @@ use alloc::vec::Vec;
 
 use rand::{Rng, seq::IndexedRandom};
 
-use heur_core::{eval::Eval, op::Operator, solution::Population};
+use heur_core::{
+    eval::Eval,
+    op::{Operator, Reset},
+    solution::Population,
+};
+
+#[cfg(feature = "rayon")]
+use rand::seq::index;
+
+#[cfg(feature = "rayon")]
+use heur_core::eval::{Fork, par_eval};
+
+#[cfg(feature = "rayon")]
+use crate::Parallel;
 
 use super::Select;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[must_use]
 pub struct TournamentSelector<R> {
     // TODO: Should these be `NonZeroUsize`?
@@ -28,6 +43,29 @@ impl<R> TournamentSelector<R> {
             rng,
         }
     }
+
+    #[cfg(feature = "rayon")]
+    pub fn parallel(self) -> Parallel<Self> {
+        Parallel(self)
+    }
+
+    fn check_sizes(&self, population_size: usize) -> Result<(), TournamentSelectError> {
+        // Ensure that we can run tournaments with `tournament_size` individuals
+        if self.tournament_size > population_size {
+            return Err(TournamentSelectError::InvalidSize {
+                tournament_size: self.tournament_size,
+                population_size,
+            });
+        }
+
+        // Ensure that we can actually select individuals
+        // NOTE: We early return here so that the compiler can remove bounds checks from each iteration below.
+        if population_size == 0 || self.tournament_size == 0 {
+            return Err(TournamentSelectError::NoSelection);
+        }
+
+        Ok(())
+    }
 }
 
 impl<P, S, E, R> Operator<P, S, E> for TournamentSelector<R>
@@ -78,30 +116,19 @@ where
     ) -> Result<(), Self::Error> {
         let population = population.as_ref();
 
-        // Ensure that we can run tournaments with `tournament_size` individuals
-        if self.tournament_size > population.len() {
-            return Err(TournamentSelectError::InvalidSize {
-                tournament_size: self.tournament_size,
-                population_size: population.len(),
-            });
-        }
-
-        // Ensure that we can actually select individuals
-        // NOTE: We early return here so that the compiler can remove bounds checks from each iteration below.
-        if population.is_empty() || self.tournament_size == 0 {
-            return Err(TournamentSelectError::NoSelection);
-        }
+        self.check_sizes(population.len())?;
 
         // Run tournaments `selection_size` times and select the best individual from each
         // NOTE: This does not guarantee that we won't select the same individual(s) multiple times. We also don't check
         //       whether `selection_size <= population.len()`, so in case `selection_size` is larger than the number of
         //       individuals available, we will invariably end up selecting repeated individuals, but this is fine.
+        let direction = eval.direction();
         selected.clear();
         selected.reserve(self.selection_size);
         for _ in 0..self.selection_size {
             let winner = population
                 .choose_multiple(&mut self.rng, self.tournament_size)
-                .max_by_key(|solution| eval.eval(solution, problem))
+                .max_by_key(|solution| direction.rank(eval.eval(solution, problem)))
                 .cloned()
                 .unwrap(); // PANICS: We have checked above that the population is not empty and `tournament_size > 0`.
             selected.push(winner);
@@ -111,6 +138,85 @@ where
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<P, S, E, R> Operator<P, S, E> for Parallel<TournamentSelector<R>>
+where
+    S: Population<Individual: Clone + Sync> + AsRef<[S::Individual]>,
+    P: Sync,
+    E: Eval<P, S::Individual, Objective: Ord + Send> + Fork + Send + Sync,
+    R: Rng,
+{
+    type Output = Vec<S::Individual>;
+
+    type Error = TournamentSelectError;
+
+    fn apply(
+        &mut self,
+        population: &mut S,
+        problem: &P,
+        eval: &mut E,
+        _input: (),
+    ) -> Result<Self::Output, Self::Error> {
+        self.select(population, problem, eval)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<P, S, E, R> Select<P, S, E> for Parallel<TournamentSelector<R>>
+where
+    S: Population<Individual: Clone + Sync> + AsRef<[S::Individual]>,
+    P: Sync,
+    E: Eval<P, S::Individual, Objective: Ord + Send> + Fork + Send + Sync,
+    R: Rng,
+{
+    fn select(
+        &mut self,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+    ) -> Result<Vec<S::Individual>, Self::Error> {
+        let mut selected = Vec::with_capacity(self.0.selection_size);
+        self.select_into(population, problem, eval, &mut selected)?;
+        Ok(selected)
+    }
+
+    fn select_into(
+        &mut self,
+        population: &S,
+        problem: &P,
+        eval: &mut E,
+        selected: &mut Vec<S::Individual>,
+    ) -> Result<(), Self::Error> {
+        let population = population.as_ref();
+        self.0.check_sizes(population.len())?;
+
+        // Evaluate every individual in parallel up front, since the tournaments themselves need to be run sequentially
+        // NOTE: This evaluates individuals that might not take part in any tournament, which is only worth it when
+        //       evaluations are expensive and most individuals are likely to be picked anyway.
+        let direction = eval.direction();
+        let objectives = par_eval(population, problem, eval);
+
+        // Run tournaments `selection_size` times and select the best individual from each
+        // NOTE: See the notes in the sequential impl above.
+        let selector = &mut self.0;
+        selected.clear();
+        selected.reserve(selector.selection_size);
+        for _ in 0..selector.selection_size {
+            let winner = index::sample(
+                &mut selector.rng,
+                population.len(),
+                selector.tournament_size,
+            )
+            .into_iter()
+            .max_by_key(|&idx| direction.rank(&objectives[idx]))
+            .unwrap(); // PANICS: We have checked above that the population is not empty and `tournament_size > 0`.
+            selected.push(population[winner].clone());
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum TournamentSelectError {
     InvalidSize {
@@ -140,3 +246,76 @@ impl Display for TournamentSelectError {
 }
 
 impl Error for TournamentSelectError {}
+
+// NOTE: This lets operators that cannot fail be combined with this one (see `Operator::err_into`).
+impl From<Infallible> for TournamentSelectError {
+    fn from(error: Infallible) -> Self {
+        match error {}
+    }
+}
+
+impl<R> Reset for TournamentSelector<R> {
+    fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use heur_core::eval::{self, Eval};
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    // NOTE: With the whole population in every tournament, the winner does not depend on the RNG.
+    #[test]
+    fn selects_best_by_direction() {
+        let population = vec![3, 1, 4, 1, 5];
+        let mut selector = TournamentSelector::new(5, 2, StdRng::seed_from_u64(0));
+
+        let mut max = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+        let selected = selector.select(&population, &(), &mut max).unwrap();
+        assert_eq!(selected, vec![5, 5]);
+
+        let mut min = eval::from_fn(|solution: &i32, _: &()| *solution).minimize();
+        let selected = selector.select(&population, &(), &mut min).unwrap();
+        assert_eq!(selected, vec![1, 1]);
+    }
+
+    #[test]
+    fn rejects_invalid_sizes() {
+        let mut eval = eval::from_fn(|solution: &i32, _: &()| *solution);
+        let mut selector = TournamentSelector::new(3, 1, StdRng::seed_from_u64(0));
+        let error = selector.select(&vec![1, 2], &(), &mut eval).unwrap_err();
+        assert_eq!(
+            error,
+            TournamentSelectError::InvalidSize {
+                tournament_size: 3,
+                population_size: 2,
+            }
+        );
+    }
+
+    // NOTE: The parallel impl evaluates every individual up front, but samples the same tournaments from the RNG.
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_matches_sequential() {
+        let population = (0..20)
+            .map(|solution| solution * 7 % 20)
+            .collect::<Vec<i32>>();
+
+        let mut max = eval::from_fn(|solution: &i32, _: &()| *solution).maximize();
+        let mut sequential = TournamentSelector::new(3, 8, StdRng::seed_from_u64(0));
+        let mut parallel = TournamentSelector::new(3, 8, StdRng::seed_from_u64(0)).parallel();
+        assert_eq!(
+            parallel.select(&population, &(), &mut max),
+            sequential.select(&population, &(), &mut max)
+        );
+
+        let mut min = eval::from_fn(|solution: &i32, _: &()| *solution).minimize();
+        assert_eq!(
+            parallel.select(&population, &(), &mut min),
+            sequential.select(&population, &(), &mut min)
+        );
+    }
+}
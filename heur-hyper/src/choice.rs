@@ -0,0 +1,129 @@
+use alloc::boxed::Box;
+
+use heur_core::op::{
+    Reset,
+    adaptive::{OperatorStats, SelectionRule},
+};
+
+mod random;
+pub use random::SimpleRandom;
+
+mod greedy;
+pub use greedy::Greedy;
+
+mod reinforcement;
+pub use reinforcement::ReinforcementLearning;
+
+mod scoring;
+pub use scoring::Scoring;
+
+// NOTE: A choice function decides which low-level heuristic to apply next, given the statistics of every heuristic so far
+//       (with one entry per heuristic), and is then told the reward the chosen heuristic was given. This is like
+//       `SelectionRule`, except that choice functions can also look ahead (see `lookahead`). Selection rules can be used
+//       as choice functions using `Rule`.
+// TODO: Add `#[diagnostic::on_unimplemented]`
+pub trait ChoiceFunction: Reset {
+    // NOTE: If this returns `true`, every heuristic is applied and credited, and the one that produced the best solution
+    //       (by objective value) is chosen without calling `select`.
+    #[must_use]
+    fn lookahead(&self) -> bool {
+        false
+    }
+
+    // NOTE: This is never called with empty `stats`.
+    #[must_use]
+    fn select(&mut self, stats: &[OperatorStats]) -> usize;
+
+    fn update(&mut self, index: usize, reward: f64, stats: &[OperatorStats]);
+}
+
+impl<T> ChoiceFunction for &mut T
+where
+    T: ChoiceFunction + ?Sized,
+{
+    fn lookahead(&self) -> bool {
+        T::lookahead(self)
+    }
+
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        T::select(self, stats)
+    }
+
+    fn update(&mut self, index: usize, reward: f64, stats: &[OperatorStats]) {
+        T::update(self, index, reward, stats)
+    }
+}
+
+impl<T> ChoiceFunction for Box<T>
+where
+    T: ChoiceFunction + ?Sized,
+{
+    fn lookahead(&self) -> bool {
+        T::lookahead(self)
+    }
+
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        T::select(self, stats)
+    }
+
+    fn update(&mut self, index: usize, reward: f64, stats: &[OperatorStats]) {
+        T::update(self, index, reward, stats)
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L, R> ChoiceFunction for either::Either<L, R>
+where
+    L: ChoiceFunction,
+    R: ChoiceFunction,
+{
+    fn lookahead(&self) -> bool {
+        match self {
+            Self::Left(left) => left.lookahead(),
+            Self::Right(right) => right.lookahead(),
+        }
+    }
+
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        match self {
+            Self::Left(left) => left.select(stats),
+            Self::Right(right) => right.select(stats),
+        }
+    }
+
+    fn update(&mut self, index: usize, reward: f64, stats: &[OperatorStats]) {
+        match self {
+            Self::Left(left) => left.update(index, reward, stats),
+            Self::Right(right) => right.update(index, reward, stats),
+        }
+    }
+}
+
+// NOTE: This lets the selection rules used for adaptive operator selection (such as `ProbabilityMatching` and `Ucb1`) be
+//       used as choice functions.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Rule<T>(pub T);
+
+impl<T> ChoiceFunction for Rule<T>
+where
+    T: SelectionRule,
+{
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        self.0.select(stats)
+    }
+
+    fn update(&mut self, index: usize, reward: f64, stats: &[OperatorStats]) {
+        self.0.update(index, reward, stats);
+    }
+}
+
+impl<T> Reset for Rule<T>
+where
+    T: Reset,
+{
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
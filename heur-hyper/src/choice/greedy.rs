@@ -0,0 +1,43 @@
+use heur_core::op::{Reset, adaptive::OperatorStats};
+
+use super::ChoiceFunction;
+
+// NOTE: This applies every heuristic and chooses the one that produced the best solution (see
+//       `ChoiceFunction::lookahead`), even if none of them improved on the current one. Ties are broken in favour of the
+//       heuristic that comes first.
+//
+//       If it is ever asked to choose without looking ahead, it chooses the heuristic with the highest mean reward.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Greedy;
+
+impl Greedy {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ChoiceFunction for Greedy {
+    fn lookahead(&self) -> bool {
+        true
+    }
+
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        (0..stats.len())
+            .reduce(|best, idx| {
+                if stats[idx].mean_reward() > stats[best].mean_reward() {
+                    idx
+                } else {
+                    best
+                }
+            })
+            .unwrap() // PANICS: `stats` is never empty.
+    }
+
+    fn update(&mut self, _index: usize, _reward: f64, _stats: &[OperatorStats]) {}
+}
+
+impl Reset for Greedy {
+    fn reset(&mut self) {}
+}
@@ -0,0 +1,34 @@
+use heur_core::op::{Reset, adaptive::OperatorStats};
+
+use rand::Rng;
+
+use super::ChoiceFunction;
+
+// NOTE: This chooses every heuristic with the same probability, regardless of how well it has performed.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct SimpleRandom<R> {
+    rng: R,
+}
+
+impl<R> SimpleRandom<R> {
+    pub fn new(rng: R) -> Self {
+        Self { rng }
+    }
+}
+
+impl<R> ChoiceFunction for SimpleRandom<R>
+where
+    R: Rng,
+{
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        self.rng.random_range(0..stats.len())
+    }
+
+    fn update(&mut self, _index: usize, _reward: f64, _stats: &[OperatorStats]) {}
+}
+
+impl<R> Reset for SimpleRandom<R> {
+    fn reset(&mut self) {}
+}
@@ -0,0 +1,78 @@
+use alloc::vec::Vec;
+
+use heur_core::op::{Reset, adaptive::OperatorStats};
+
+use rand::Rng;
+
+use super::ChoiceFunction;
+
+// NOTE: Every heuristic has a utility score, which starts out at the initial utility and goes up by one whenever the
+//       heuristic is given a positive reward (and down by one otherwise), between zero and the maximum utility. The
+//       heuristic with the highest utility is chosen, with ties broken at random.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct ReinforcementLearning<R> {
+    initial_utility: usize,
+    max_utility: usize,
+    utilities: Vec<usize>,
+    rng: R,
+}
+
+impl<R> ReinforcementLearning<R> {
+    pub fn new(initial_utility: usize, max_utility: usize, rng: R) -> Self {
+        Self {
+            initial_utility,
+            max_utility,
+            utilities: Vec::new(),
+            rng,
+        }
+    }
+
+    pub fn utilities(&self) -> &[usize] {
+        &self.utilities
+    }
+}
+
+impl<R> ChoiceFunction for ReinforcementLearning<R>
+where
+    R: Rng,
+{
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        let initial_utility = self.initial_utility.min(self.max_utility);
+        self.utilities.resize(stats.len(), initial_utility);
+
+        // NOTE: This picks uniformly at random between the heuristics with the highest utility by only replacing the
+        //       current choice with the `n`th tied heuristic with probability `1 / n`.
+        let max_utility = self.utilities.iter().copied().max().unwrap_or_default();
+        let mut best = 0;
+        let mut ties = 0;
+        for (idx, utility) in self.utilities.iter().enumerate() {
+            if *utility == max_utility {
+                ties += 1;
+                if self.rng.random_range(0..ties) == 0 {
+                    best = idx;
+                }
+            }
+        }
+        best
+    }
+
+    fn update(&mut self, index: usize, reward: f64, stats: &[OperatorStats]) {
+        let initial_utility = self.initial_utility.min(self.max_utility);
+        self.utilities.resize(stats.len(), initial_utility);
+
+        let utility = &mut self.utilities[index];
+        *utility = if reward > 0.0 {
+            (*utility + 1).min(self.max_utility)
+        } else {
+            utility.saturating_sub(1)
+        };
+    }
+}
+
+impl<R> Reset for ReinforcementLearning<R> {
+    fn reset(&mut self) {
+        self.utilities.clear();
+    }
+}
@@ -0,0 +1,100 @@
+use alloc::vec::Vec;
+
+use heur_core::op::{Reset, adaptive::OperatorStats};
+
+use super::ChoiceFunction;
+
+// NOTE: This scores every heuristic by a weighted sum of how well it has performed recently, how well it has performed
+//       when applied right after the previously chosen heuristic, and how many iterations it has been since it was last
+//       chosen, and chooses the one with the highest score. The first two are sums of past rewards, where every reward
+//       is discounted by the respective weight each time the heuristic (or pair of heuristics) is chosen again. The weights
+//       of the first two should therefore be between 0 and 1.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct Scoring {
+    intensification: f64,
+    pairing: f64,
+    diversification: f64,
+    recent: Vec<f64>,
+    // NOTE: This is a row-major matrix indexed by the previous heuristic and then the next one.
+    pairs: Vec<f64>,
+    last_chosen: Vec<usize>,
+    prev: Option<usize>,
+    iteration: usize,
+}
+
+impl Scoring {
+    pub fn new(intensification: f64, pairing: f64, diversification: f64) -> Self {
+        Self {
+            intensification,
+            pairing,
+            diversification,
+            recent: Vec::new(),
+            pairs: Vec::new(),
+            last_chosen: Vec::new(),
+            prev: None,
+            iteration: 0,
+        }
+    }
+
+    // NOTE: The scores start over if the number of heuristics changes.
+    fn resize(&mut self, len: usize) {
+        if self.recent.len() != len {
+            self.reset();
+            self.recent.resize(len, 0.0);
+            self.pairs.resize(len * len, 0.0);
+            self.last_chosen.resize(len, 0);
+        }
+    }
+
+    fn score(&self, index: usize) -> f64 {
+        let len = self.recent.len();
+        let pair = self.prev.map_or(0.0, |prev| self.pairs[prev * len + index]);
+        let elapsed = (self.iteration - self.last_chosen[index]) as f64;
+        self.intensification * self.recent[index]
+            + self.pairing * pair
+            + self.diversification * elapsed
+    }
+}
+
+impl ChoiceFunction for Scoring {
+    fn select(&mut self, stats: &[OperatorStats]) -> usize {
+        self.resize(stats.len());
+
+        // NOTE: Ties are broken in favour of the heuristic that comes first.
+        let mut best = (0, f64::NEG_INFINITY);
+        for idx in 0..stats.len() {
+            let score = self.score(idx);
+            if score > best.1 {
+                best = (idx, score);
+            }
+        }
+        best.0
+    }
+
+    fn update(&mut self, index: usize, reward: f64, stats: &[OperatorStats]) {
+        self.resize(stats.len());
+
+        let len = self.recent.len();
+        self.recent[index] = reward + self.intensification * self.recent[index];
+        if let Some(prev) = self.prev {
+            let pair = &mut self.pairs[prev * len + index];
+            *pair = reward + self.pairing * *pair;
+        }
+
+        self.iteration += 1;
+        self.last_chosen[index] = self.iteration;
+        self.prev = Some(index);
+    }
+}
+
+impl Reset for Scoring {
+    fn reset(&mut self) {
+        self.recent.clear();
+        self.pairs.clear();
+        self.last_chosen.clear();
+        self.prev = None;
+        self.iteration = 0;
+    }
+}
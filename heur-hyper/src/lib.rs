@@ -0,0 +1,387 @@
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+#![deny(rust_2018_idioms)]
+// #![warn(missing_docs)] // TODO: Enable once finished
+#![deny(rustdoc::broken_intra_doc_links)]
+#![no_std]
+
+extern crate alloc;
+
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use alloc::vec::Vec;
+
+use heur_core::{
+    Optimize,
+    eval::Eval,
+    observe::Event,
+    op::{
+        OperatorList,
+        Reset,
+        accept::Accept,
+        adaptive::{Credit, OperatorStats},
+        init::Init,
+        stop::Stop,
+    },
+    solution::Individual,
+};
+
+use choice::ChoiceFunction;
+
+pub mod choice;
+
+// NOTE: Every iteration, the choice function picks one of the low-level heuristics, which is applied to a copy of the
+//       current solution. The heuristic is credited based on the objective value before and after (see `Credit`), and the
+//       new solution then replaces the current one if the acceptance criterion accepts it. Choice functions that look
+//       ahead (such as `Greedy`) have every heuristic applied and credited, and the best of the new solutions is used.
+//       The best solution found during the run is returned, even if the acceptance criterion later moved away from it.
+//
+//       The heuristics can be any list of operators (see `OperatorList`), such as a `Vec` of boxed operators created using
+//       `Operator::boxed`. Running this with an empty list of heuristics fails with
+//       `SelectionHyperHeuristicError::NoHeuristics`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use]
+pub struct SelectionHyperHeuristic<Ini, H, C, K, A, Sto> {
+    init: Ini,
+    heuristics: H,
+    choice: C,
+    credit: K,
+    accept: A,
+    stop: Sto,
+    stats: Vec<OperatorStats>,
+}
+
+impl<Ini, H, C, K, A, Sto> SelectionHyperHeuristic<Ini, H, C, K, A, Sto> {
+    pub fn new(init: Ini, heuristics: H, choice: C, credit: K, accept: A, stop: Sto) -> Self {
+        Self {
+            init,
+            heuristics,
+            choice,
+            credit,
+            accept,
+            stop,
+            stats: Vec::new(),
+        }
+    }
+
+    pub fn heuristics(&self) -> &H {
+        &self.heuristics
+    }
+
+    pub fn heuristics_mut(&mut self) -> &mut H {
+        &mut self.heuristics
+    }
+
+    pub fn choice(&self) -> &C {
+        &self.choice
+    }
+
+    pub fn stop(&self) -> &Sto {
+        &self.stop
+    }
+
+    // NOTE: This has one entry per low-level heuristic once the solver has been run.
+    pub fn stats(&self) -> &[OperatorStats] {
+        &self.stats
+    }
+
+    fn record(&mut self, index: usize, reward: f64)
+    where
+        C: ChoiceFunction,
+    {
+        let stats = &mut self.stats[index];
+        stats.applications += 1;
+        stats.total_reward += reward;
+        if reward > 0.0 {
+            stats.successes += 1;
+        }
+        self.choice.update(index, reward, &self.stats);
+    }
+}
+
+impl<Ini, H, C, K, A, Sto, P, S, E> Optimize<P, Individual<S>, E>
+    for SelectionHyperHeuristic<Ini, H, C, K, A, Sto>
+where
+    Ini: Init<P, Individual<S>, E, Output = (), Error: 'static> + Reset,
+    H: OperatorList<P, Individual<S>, E, Output = (), Error = Ini::Error> + Reset,
+    C: ChoiceFunction,
+    K: Credit<E::Objective>,
+    A: Accept<P, Individual<S>, E> + Reset,
+    Sto: Stop<P, Individual<S>, E> + Reset,
+    S: Clone,
+    E: Eval<P, S>,
+{
+    type Error = SelectionHyperHeuristicError<Ini::Error>;
+
+    fn optimize(&mut self, problem: &P, eval: &mut E) -> Result<Individual<S>, Self::Error> {
+        // NOTE: This lets the same solver be run more than once (see `Reset`). The statistics (and the choice function's
+        //       estimates, which are based on them) only describe a single run.
+        self.reset();
+        let len = self.heuristics.len();
+        if len == 0 {
+            return Err(SelectionHyperHeuristicError::NoHeuristics);
+        }
+        self.stats.resize(len, OperatorStats::default());
+
+        let direction = eval.direction();
+        let mut solution = self
+            .init
+            .init(problem, eval)
+            .map_err(SelectionHyperHeuristicError::Operator)?;
+        let mut best = solution.clone();
+        let mut best_objective = eval.eval(&best, problem);
+        eval.observe(Event::NewBest {
+            objective: &best_objective,
+        });
+
+        let mut iteration = 0;
+        while !self.stop.stop_with_cause(&solution, problem, eval) {
+            eval.observe(Event::IterationStarted { iteration });
+
+            let prev_objective = eval.eval(&solution, problem);
+            let (candidate, objective) = if self.choice.lookahead() {
+                let mut candidates = Vec::with_capacity(len);
+                for idx in 0..len {
+                    let mut candidate = solution.clone();
+                    self.heuristics
+                        .apply_nth(idx, &mut candidate, problem, eval, ())
+                        .map_err(SelectionHyperHeuristicError::Operator)?;
+                    eval.observe(Event::list_operator_applied(&self.heuristics, idx));
+
+                    let objective = eval.eval(&candidate, problem);
+                    let reward = self.credit.credit(&objective, &prev_objective, direction);
+                    self.record(idx, reward);
+                    candidates.push((candidate, objective));
+                }
+                let idx = (0..len)
+                    .reduce(|chosen, idx| {
+                        if direction.rank(&candidates[idx].1)
+                            > direction.rank(&candidates[chosen].1)
+                        {
+                            idx
+                        } else {
+                            chosen
+                        }
+                    })
+                    .unwrap(); // PANICS: We have checked above that there is at least one heuristic.
+                candidates.swap_remove(idx)
+            } else {
+                let idx = self.choice.select(&self.stats);
+                let mut candidate = solution.clone();
+                self.heuristics
+                    .apply_nth(idx, &mut candidate, problem, eval, ())
+                    .map_err(SelectionHyperHeuristicError::Operator)?;
+                eval.observe(Event::list_operator_applied(&self.heuristics, idx));
+
+                let objective = eval.eval(&candidate, problem);
+                let reward = self.credit.credit(&objective, &prev_objective, direction);
+                self.record(idx, reward);
+                (candidate, objective)
+            };
+
+            // NOTE: The acceptance criterion may accept worse solutions (see `op::accept`), so the best solution found is
+            //       kept separately and returned at the end.
+            if direction.rank(&objective) > direction.rank(&best_objective) {
+                best.clone_from(&candidate);
+                best_objective = objective;
+                eval.observe(Event::NewBest {
+                    objective: &best_objective,
+                });
+            }
+
+            if self.accept.accept(&candidate, &solution, problem, eval) {
+                eval.observe(Event::Accepted);
+                solution = candidate;
+            } else {
+                eval.observe(Event::Rejected);
+            }
+
+            eval.observe(Event::IterationFinished { iteration });
+            iteration += 1;
+        }
+        eval.observe(Event::Stopped {
+            iterations: iteration,
+        });
+
+        Ok(best)
+    }
+}
+
+// NOTE: `Operator` wraps the error of the initialization or of one of the low-level heuristics, which is returned by
+//       `source`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SelectionHyperHeuristicError<Err> {
+    NoHeuristics,
+    Operator(Err),
+}
+
+impl<Err> Display for SelectionHyperHeuristicError<Err> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoHeuristics => write!(formatter, "cannot select a heuristic from an empty list"),
+            Self::Operator(_) => write!(formatter, "operator failed"),
+        }
+    }
+}
+
+impl<Err> Error for SelectionHyperHeuristicError<Err>
+where
+    Err: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NoHeuristics => None,
+            Self::Operator(error) => Some(error),
+        }
+    }
+}
+
+impl<Ini, H, C, K, A, Sto> Reset for SelectionHyperHeuristic<Ini, H, C, K, A, Sto>
+where
+    Ini: Reset,
+    H: Reset,
+    C: Reset,
+    A: Reset,
+    Sto: Reset,
+{
+    fn reset(&mut self) {
+        self.init.reset();
+        self.heuristics.reset();
+        self.choice.reset();
+        self.accept.reset();
+        self.stop.reset();
+        self.stats.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use heur_core::{
+        eval,
+        op::{
+            Operator,
+            accept::{Always, Improving},
+            adaptive::Improvement,
+            init,
+            stop::Iterations,
+        },
+    };
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::choice::{Greedy, SimpleRandom};
+
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    struct Add(i32);
+
+    impl<P, E> Operator<P, Individual<i32>, E> for Add
+    where
+        E: Eval<P, i32>,
+    {
+        type Output = ();
+
+        type Error = Infallible;
+
+        fn apply(
+            &mut self,
+            solution: &mut Individual<i32>,
+            _problem: &P,
+            _eval: &mut E,
+            _input: (),
+        ) -> Result<Self::Output, Self::Error> {
+            **solution += self.0;
+            Ok(())
+        }
+    }
+
+    impl Reset for Add {
+        fn reset(&mut self) {}
+    }
+
+    fn distance(solution: &i32, _problem: &()) -> i32 {
+        -(solution - 7).abs()
+    }
+
+    #[test]
+    fn greedy_applies_and_credits_every_heuristic() {
+        let mut eval = eval::from_fn(|solution: &i32, _problem: &()| *solution);
+        let mut solver = SelectionHyperHeuristic::new(
+            init::from_individual(0),
+            [Add(1), Add(-1), Add(3)],
+            Greedy::new(),
+            Improvement::new(),
+            Improving::new(),
+            Iterations::new(3),
+        );
+
+        assert_eq!(*solver.optimize(&(), &mut eval).unwrap(), 9);
+        let stats = solver.stats();
+        assert!(stats.iter().all(|stats| stats.applications == 3));
+        assert_eq!(
+            stats
+                .iter()
+                .map(|stats| stats.successes)
+                .collect::<Vec<_>>(),
+            [3, 0, 3]
+        );
+        assert!(stats[2].total_reward > stats[0].total_reward);
+    }
+
+    #[test]
+    fn returns_best_solution_after_accepting_worse_ones() {
+        let mut eval = eval::from_fn(distance);
+        let mut solver = SelectionHyperHeuristic::new(
+            init::from_individual(0),
+            [Add(7), Add(1)],
+            Greedy::new(),
+            Improvement::new(),
+            Always::new(),
+            Iterations::new(5),
+        );
+
+        // 0 -> 7 -> 8 -> ... -> 11, where every step after the first one moves away from 7
+        assert_eq!(*solver.optimize(&(), &mut eval).unwrap(), 7);
+    }
+
+    #[test]
+    fn random_choice_applies_one_heuristic_per_iteration() {
+        let mut eval = eval::from_fn(distance);
+        let mut solver = SelectionHyperHeuristic::new(
+            init::from_individual(0),
+            [Add(1), Add(-1)],
+            SimpleRandom::new(StdRng::seed_from_u64(0)),
+            Improvement::new(),
+            Improving::new(),
+            Iterations::new(100),
+        );
+
+        assert_eq!(*solver.optimize(&(), &mut eval).unwrap(), 7);
+        let stats = solver.stats();
+        assert_eq!(stats[0].applications + stats[1].applications, 100);
+        assert_eq!((stats[0].successes, stats[1].successes), (7, 0));
+    }
+
+    #[test]
+    fn empty_heuristic_list_fails() {
+        let mut eval = eval::from_fn(distance);
+        let mut solver = SelectionHyperHeuristic::new(
+            init::from_individual(0),
+            Vec::<Add>::new(),
+            Greedy::new(),
+            Improvement::new(),
+            Improving::new(),
+            Iterations::new(5),
+        );
+
+        assert_eq!(
+            solver.optimize(&(), &mut eval),
+            Err(SelectionHyperHeuristicError::NoHeuristics)
+        );
+    }
+}
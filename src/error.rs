@@ -0,0 +1,177 @@
+use core::{
+    convert::Infallible,
+    error,
+    fmt::{self, Display, Formatter},
+};
+
+#[cfg(feature = "alloc")]
+use heur_core::op::ChooseWeightedError;
+
+#[cfg(feature = "genetic")]
+use heur_genetic::{combine::UniformCrossoverError, select::TournamentSelectError};
+
+// NOTE: This wraps the error types of every built-in operator, so that operators with different error types can be
+//       combined (e.g. using `Operator::then` or `Either`) after converting their errors into it using
+//       `Operator::err_into`. Variants are only present if the crate that defines their error type is enabled.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    #[cfg(feature = "alloc")]
+    ChooseWeighted(ChooseWeightedError),
+    #[cfg(feature = "genetic")]
+    TournamentSelect(TournamentSelectError),
+    #[cfg(feature = "genetic")]
+    UniformCrossover(UniformCrossoverError),
+}
+
+// NOTE: The message only says which operator failed, since the error that caused it is returned by `source`.
+impl Error {
+    fn message(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "alloc")]
+            Self::ChooseWeighted(_) => "weighted choice failed",
+            #[cfg(feature = "genetic")]
+            Self::TournamentSelect(_) => "tournament selection failed",
+            #[cfg(feature = "genetic")]
+            Self::UniformCrossover(_) => "uniform crossover failed",
+        }
+    }
+
+    fn inner(&self) -> &(dyn error::Error + 'static) {
+        match *self {
+            #[cfg(feature = "alloc")]
+            Self::ChooseWeighted(ref error) => error,
+            #[cfg(feature = "genetic")]
+            Self::TournamentSelect(ref error) => error,
+            #[cfg(feature = "genetic")]
+            Self::UniformCrossover(ref error) => error,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.message())
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(self.inner())
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(error: Infallible) -> Self {
+        match error {}
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<ChooseWeightedError> for Error {
+    fn from(error: ChooseWeightedError) -> Self {
+        Self::ChooseWeighted(error)
+    }
+}
+
+#[cfg(feature = "genetic")]
+impl From<TournamentSelectError> for Error {
+    fn from(error: TournamentSelectError) -> Self {
+        Self::TournamentSelect(error)
+    }
+}
+
+#[cfg(feature = "genetic")]
+impl From<UniformCrossoverError> for Error {
+    fn from(error: UniformCrossoverError) -> Self {
+        Self::UniformCrossover(error)
+    }
+}
+
+#[cfg(all(test, feature = "alloc", feature = "genetic"))]
+mod tests {
+    use core::error::Error as _;
+
+    use heur_core::{
+        eval,
+        op::{self, Operator},
+    };
+    use heur_genetic::{combine::UniformCrossover, select::TournamentSelector};
+    use rand::{SeedableRng, distr::Bernoulli, rngs::StdRng};
+
+    use super::*;
+
+    type Population = Vec<Vec<bool>>;
+
+    type Ones = eval::FromFn<fn(&Vec<bool>, &Population) -> usize>;
+
+    fn pipeline(
+        tournament_size: usize,
+        selection_size: usize,
+    ) -> impl Operator<Population, Population, Ones, Output = usize, Error = Error> {
+        let select =
+            TournamentSelector::new(tournament_size, selection_size, StdRng::seed_from_u64(0));
+        let combine = UniformCrossover::new(Bernoulli::new(0.5).unwrap(), StdRng::seed_from_u64(0));
+        let count = op::from_fn(
+            |_: &mut Population, _: &Population, _: &mut _, offspring: Vec<Vec<bool>>| {
+                Ok::<_, Infallible>(offspring.len())
+            },
+        );
+        op::hint(select)
+            .err_into::<Error>()
+            .pipe(op::hint(combine).err_into())
+            .pipe(op::hint(count).err_into())
+    }
+
+    #[test]
+    fn errors_of_combined_operators_convert_into_error() {
+        let ones: fn(&Vec<bool>, &Population) -> usize =
+            |solution, _| solution.iter().filter(|bit| **bit).count();
+        let mut eval = eval::from_fn(ones);
+        let mut population = vec![vec![false, true], vec![true, true], vec![true, false]];
+        let problem = population.clone();
+
+        let offspring = pipeline(2, 2).apply(&mut population, &problem, &mut eval, ());
+        assert_eq!(offspring, Ok(2));
+
+        let error = pipeline(4, 2)
+            .apply(&mut population, &problem, &mut eval, ())
+            .unwrap_err();
+        assert_eq!(
+            error,
+            Error::TournamentSelect(TournamentSelectError::InvalidSize {
+                tournament_size: 4,
+                population_size: 3,
+            })
+        );
+
+        let error = pipeline(2, 3)
+            .apply(&mut population, &problem, &mut eval, ())
+            .unwrap_err();
+        assert_eq!(
+            error,
+            Error::UniformCrossover(UniformCrossoverError::InvalidSize { selection_size: 3 })
+        );
+    }
+
+    #[test]
+    fn source_is_wrapped_error() {
+        let inner = ChooseWeightedError::InvalidLength { ops: 3, weights: 2 };
+        let error = Error::from(inner);
+        assert_eq!(error.to_string(), "weighted choice failed");
+        let source = error.source().unwrap();
+        assert_eq!(source.to_string(), inner.to_string());
+        assert_eq!(source.downcast_ref::<ChooseWeightedError>(), Some(&inner));
+
+        let inner = UniformCrossoverError::InvalidSize { selection_size: 3 };
+        let error = Error::from(inner);
+        assert_eq!(error.to_string(), "uniform crossover failed");
+        assert_eq!(
+            error
+                .source()
+                .unwrap()
+                .downcast_ref::<UniformCrossoverError>(),
+            Some(&inner)
+        );
+    }
+}
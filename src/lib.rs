@@ -6,6 +6,9 @@
 #[doc(inline)]
 pub use heur_core::*;
 
+mod error;
+pub use error::Error;
+
 #[cfg(feature = "bits")]
 #[doc(inline)]
 pub use heur_bits as bits;
@@ -13,3 +16,7 @@ pub use heur_bits as bits;
 #[cfg(feature = "genetic")]
 #[doc(inline)]
 pub use heur_genetic as genetic;
+
+#[cfg(feature = "hyper")]
+#[doc(inline)]
+pub use heur_hyper as hyper;